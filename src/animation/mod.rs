@@ -0,0 +1,90 @@
+//! Recording of grid simulations as snapshots - for image export (see `crate::image`) and terminal playback
+//! (see `crate::playback`).
+
+pub mod y2024;
+
+use crate::{
+    error::Result,
+    image::{Image, Rgb},
+    params::Params,
+    registry::Part,
+    Grid,
+};
+
+/// State of the simulation after `iteration` steps, grid in the puzzle notation.
+pub struct Snapshot {
+    pub iteration: usize,
+    pub grid: Grid<char>,
+    /// Named values describing the state, e.g. visited position count.
+    pub metrics: Vec<(&'static str, usize)>,
+}
+
+impl Snapshot {
+    pub fn new(iteration: usize, grid: Grid<char>) -> Self {
+        Self {
+            iteration,
+            grid,
+            metrics: vec![],
+        }
+    }
+
+    pub fn from_text(iteration: usize, text: &str) -> Self {
+        Self::new(
+            iteration,
+            Grid::new(text.lines().map(|line| line.chars().collect()).collect()),
+        )
+    }
+
+    pub fn with_metric(mut self, name: &'static str, value: usize) -> Self {
+        self.metrics.push((name, value));
+        self
+    }
+
+    pub fn to_image(&self, scale: usize, colour: fn(&char) -> Rgb) -> Image {
+        Image::from_grid(&self.grid, scale, colour)
+    }
+}
+
+/// Which iterations to record - `start`, `start + step`, ... at most `frames` snapshots.
+/// Simulation that ends earlier adds its final state as the last snapshot, even if it's not on a step.
+#[derive(Clone, Copy, Debug)]
+pub struct Recording {
+    pub start: usize,
+    pub step: usize,
+    pub frames: usize,
+}
+
+impl Recording {
+    pub fn is_recorded(&self, iteration: usize) -> bool {
+        iteration >= self.start && (iteration - self.start).is_multiple_of(self.step.max(1))
+    }
+
+    /// Iterations to record, in order.
+    pub fn iterations(&self) -> impl Iterator<Item = usize> {
+        let (start, step) = (self.start, self.step.max(1));
+        (0..self.frames).map(move |i| start + i * step)
+    }
+}
+
+/// Simulation of one puzzle part.
+pub struct Animation {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub record: fn(&str, &Params, &Recording) -> Result<Vec<Snapshot>>,
+    pub colour: fn(&char) -> Rgb,
+}
+
+/// All registered animations.
+pub fn animations() -> impl Iterator<Item = &'static Animation> {
+    y2024::ANIMATIONS.iter()
+}
+
+/// Animation of the part, the day's first animation if `part` is `None`.
+pub fn find(year: u16, day: u8, part: Option<Part>) -> Option<&'static Animation> {
+    animations().find(|animation| {
+        animation.year == year
+            && animation.day == day
+            && part.is_none_or(|part| animation.part == part)
+    })
+}
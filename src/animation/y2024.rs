@@ -0,0 +1,61 @@
+//! Animations of https://adventofcode.com/2024 simulations.
+
+use super::Animation;
+use crate::{
+    image::{Rgb, BLACK},
+    registry::Part,
+    y2024::{day06, day14, day15},
+};
+
+const YEAR: u16 = 2024;
+
+const WALL: Rgb = Rgb(90, 90, 100);
+const FLOOR: Rgb = Rgb(20, 20, 30);
+
+pub const ANIMATIONS: [Animation; 4] = [
+    Animation {
+        year: YEAR,
+        day: 6,
+        part: Part::One,
+        record: |input, _, recording| day06::record_walk(input, recording),
+        colour: |point| match point {
+            '#' => WALL,
+            'X' => Rgb(40, 120, 60),
+            '^' | '>' | 'v' | '<' => Rgb(255, 220, 0),
+            _ => FLOOR,
+        },
+    },
+    Animation {
+        year: YEAR,
+        day: 14,
+        part: Part::Two,
+        record: day14::record_robots,
+        colour: |point| match point {
+            'X' => Rgb(60, 200, 80),
+            _ => BLACK,
+        },
+    },
+    Animation {
+        year: YEAR,
+        day: 15,
+        part: Part::One,
+        record: |input, _, recording| day15::record_moves(input, false, recording),
+        colour: warehouse_colour,
+    },
+    Animation {
+        year: YEAR,
+        day: 15,
+        part: Part::Two,
+        record: |input, _, recording| day15::record_moves(input, true, recording),
+        colour: warehouse_colour,
+    },
+];
+
+fn warehouse_colour(point: &char) -> Rgb {
+    match point {
+        '#' => WALL,
+        'O' | '[' | ']' => Rgb(180, 120, 50),
+        '@' => Rgb(255, 60, 60),
+        _ => FLOOR,
+    }
+}
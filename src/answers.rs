@@ -0,0 +1,93 @@
+//! Store of known (accepted) answers, used to flag inputs whose answers disagree.
+//!
+//! File format - one input per line, whitespace separated, `-` for unknown answer, `#` starts a comment:
+//! ```text
+//! # year day input part_one part_two
+//! 2024 5 alice 4924 6085
+//! 2024 5 bob 5123 -
+//! ```
+//! Input is identified by its file name without extension (`resources/2024/05/alice.txt` => `alice`).
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+use crate::{
+    error::{column_of, Error, Result},
+    registry::Part,
+};
+
+pub const ANSWERS_PATH: &str = "./resources/answers.txt";
+
+const UNKNOWN_ANSWER: &str = "-";
+
+#[derive(Default)]
+pub struct AnswerStore {
+    answers: HashMap<(u16, u8, String), [Option<String>; 2]>,
+}
+
+impl AnswerStore {
+    /// Load store from file. Missing file is an empty store.
+    pub fn load(file_path: &str) -> Result<Self> {
+        match fs::read_to_string(file_path) {
+            Ok(content) => Self::parse(&content).map_err(|err| err.with_file(file_path)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::new(format!("Failed to read file: {err}")).with_file(file_path)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut store = Self::default();
+
+        for (i, line) in content.lines().enumerate() {
+            let line_num = i + 1;
+            let line = line.split('#').next().unwrap_or_default();
+            let parts: Vec<&str> = line.split_whitespace().collect();
+
+            if parts.is_empty() {
+                continue;
+            }
+
+            let [year, day, input, part_one, part_two] = parts[..] else {
+                return Err(Error::at_line(
+                    line_num,
+                    "Expected `year day input part_one part_two`.",
+                ));
+            };
+
+            let invalid_value = |value: &str| {
+                Error::at(
+                    line_num,
+                    column_of(line, value),
+                    format!("Invalid value '{value}'."),
+                )
+            };
+            let year = year.parse::<u16>().map_err(|_| invalid_value(year))?;
+            let day = day.parse::<u8>().map_err(|_| invalid_value(day))?;
+            let answer = |value: &str| match value {
+                UNKNOWN_ANSWER => None,
+                _ => Some(value.to_string()),
+            };
+
+            store.answers.insert(
+                (year, day, input.to_string()),
+                [answer(part_one), answer(part_two)],
+            );
+        }
+
+        Ok(store)
+    }
+
+    pub fn get(&self, year: u16, day: u8, input: &str, part: Part) -> Option<&str> {
+        let answers = self.answers.get(&(year, day, input.to_string()))?;
+
+        answers[part.number() - 1].as_deref()
+    }
+}
+
+/// Input identifier used by the store - file name without extension.
+pub fn input_name(file_path: &str) -> String {
+    Path::new(file_path)
+        .file_stem()
+        .map_or(file_path.to_string(), |stem| {
+            stem.to_string_lossy().to_string()
+        })
+}
@@ -0,0 +1,625 @@
+use std::{
+    collections::HashMap,
+    env, mem, panic,
+    path::Path,
+    process::ExitCode,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use advent_of_code::{
+    animation::{self, Animation, Recording},
+    answers::{input_name, AnswerStore},
+    config::Config,
+    error::{Error, Result},
+    fuzz, generate, image,
+    input::{list_inputs, read_input},
+    leaderboard::Leaderboard,
+    params::Params,
+    playback,
+    registry::{self, Outcome, Part, Solution},
+    runner::{format_report, run_inputs},
+    stats::{self, Entry, Event, Ledger},
+    trace,
+    watch::watch,
+};
+
+const USAGE: &str = "Usage: aoc <command> [year] <day> [path] [options]
+       aoc stats [year]
+       aoc leaderboard [year] [day] [options]
+
+Year can be omitted if `default_year` is configured.
+Solver internals are traced to stderr with -v (debug) or -vv (every iteration).
+
+Commands:
+  run       Solve the day's puzzle input(s). Path can be an input file or a directory of inputs,
+            defaults to `resources/<year>/<day>/` if it exists, `resources/<year>_<day>.txt` otherwise.
+            Answers are compared with `resources/answers.txt`, solver runs are recorded in the statistics ledger.
+            --params <name=value,...>  override puzzle parameters (see `<input>.params` sidecar files)
+  watch     Re-solve the day's input and example files whenever they change. Run from the crate root to also
+            rebuild and restart (`cargo run`) when the day's source `src/y<year>/day<NN>.rs` changes.
+            --params <name=value,...>  same as for run
+  generate  Print a random input for the day
+            --size <n>  day specific size (line count, grid side, ...)
+            --seed <n>  random seed, default 0
+  fuzz      Run the day's input parser on mutated inputs, report and save (`fuzz/artifacts/`) the first panic.
+            Corpus is seeded from the day's input and example files, path adds a file or directory.
+            --target <name>   day specific target (the parser by default) or `solve` for both parts of the solution
+            --iterations <n>  default 100000
+            --seed <n>        random seed, default 0
+  animate   Record the day's simulation (days with an animation) as an animated GIF or a PNG sequence.
+            Path is the input file, defaults to `resources/<year>_<day>.txt`.
+            --part <1|2>        default is the day's first animation
+            --start <n>         first recorded iteration, default 0
+            --step <n>          iterations between frames, default 1
+            --frames <n>        maximum frame count, default 100
+            --scale <n>         pixels per grid point, default 4
+            --delay <n>         GIF frame delay in hundredths of a second, default 10
+            --output <path>     `.gif` file or a directory for PNG files, default `animation_<year>_<day>.gif`
+            --params <name=value,...>  same as for run
+  play      Step through the day's simulation in the terminal: play/pause, step forward/back, jump to an iteration.
+            Path is the input file, defaults to `resources/<year>_<day>.txt`.
+            --part <1|2>  default is the day's first animation
+            --start <n>   first shown iteration, default 0
+            --fps <n>     playing speed, default 10
+            --params <name=value,...>  same as for run
+
+  submit    Record an answer attempt for the day's part in the statistics ledger and check it against
+            `resources/answers.txt`. Nothing is sent to adventofcode.com.
+            Path is the input file, defaults to `resources/<year>_<day>.txt`.
+            --part <1|2>       required
+            --answer <answer>  default is the solver's answer
+            --params <name=value,...>  same as for run
+  stats     Print solve times, attempts, solver times and streaks recorded in the statistics ledger (`stats_path`)
+  leaderboard
+            Print ranking, per-day completion times and star deltas of the private leaderboard JSON export.
+            Year is checked against the export, day limits the report to one day.
+            --source <path or http:// URL>  default is `leaderboard_source`, fetched exports are cached
+                                            in `cache_dir` for 15 minutes
+            --send-session <true|false>    send the session cookie to a local http:// stand-in, default false
+
+Configuration (`aoc.toml`, see the `config` module) can be overridden by `AOC_<NAME>` environment variables
+and by options: --config <path>, --default-year, --input-dir, --input-file, --input-day-dir, --cache-dir,
+--answers-path, --session-token-path, --stats-path, --leaderboard-source, --threads, --output-format (`table` or `tsv`).";
+
+/// Runner for all registered solutions.
+/// Run by `cargo run --bin aoc -- <command> <year> <day>`, e.g. `cargo run --bin aoc -- run 2024 9`.
+fn main() -> ExitCode {
+    let Some(args) = Args::parse(env::args().skip(1)) else {
+        return usage_error();
+    };
+    trace::init(args.verbosity);
+
+    let config = Config::load(args.options.get("config").map(String::as_str))
+        .and_then(|mut config| config.apply_options(&args.options).map(|_| config));
+    let config = match config {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some((command, rest)) = args.positional.split_first() else {
+        return usage_error();
+    };
+    let command = command.as_str();
+
+    if command == "stats" {
+        return match rest {
+            [] => stats(None, &config),
+            [year] => match year.parse() {
+                Ok(year) => stats(Some(year), &config),
+                Err(_) => usage_error(),
+            },
+            _ => usage_error(),
+        };
+    }
+
+    if command == "leaderboard" {
+        // Both year and day are optional
+        let numbers: Option<Vec<u16>> = rest.iter().map(|arg| arg.parse().ok()).collect();
+        let (year, day) = match numbers.as_deref() {
+            Some([]) => (None, None),
+            Some([year]) if *year > 25 => (Some(*year), None),
+            Some([day]) => (None, Some(*day as u8)),
+            Some([year, day]) if *year > 25 && *day <= 25 => (Some(*year), Some(*day as u8)),
+            _ => return usage_error(),
+        };
+        return leaderboard(year, day, &config, &args);
+    }
+
+    // Year is optional - days are 1..=25, anything bigger is a year
+    let numbers: Vec<Option<u16>> = rest.iter().map(|arg| arg.parse().ok()).collect();
+    let (year, day, path) = match (&numbers[..], config.default_year) {
+        ([Some(year), Some(day)] | [Some(year), Some(day), _], _) if *year > 25 => {
+            (*year, *day, rest.get(2))
+        }
+        ([Some(day)] | [Some(day), _], Some(default_year)) => (default_year, *day, rest.get(1)),
+        _ => return usage_error(),
+    };
+    let Ok(day) = u8::try_from(day) else {
+        return usage_error();
+    };
+    let path = path.cloned();
+
+    match command {
+        "generate" => return generate(year, day, &args),
+        "fuzz" => return fuzz(year, day, path, &args),
+        _ => (),
+    }
+
+    let Some(solution) = registry::find(year, day) else {
+        eprintln!("No solution registered for {year}/{day:02}.");
+        return ExitCode::FAILURE;
+    };
+
+    let overrides = match args.options.get("params") {
+        Some(overrides) => match Params::parse_overrides(overrides) {
+            Ok(overrides) => overrides,
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => Params::default(),
+    };
+
+    match command {
+        "run" => run(solution, path, &config, &overrides),
+        "watch" if path.is_none() => watch(solution, &config, &overrides),
+        "animate" => animate(solution, path, &config, &overrides, &args),
+        "play" => play(solution, path, &config, &overrides, &args),
+        "submit" => submit(solution, path, &config, &overrides, &args),
+        _ => usage_error(),
+    }
+}
+
+fn run(solution: &Solution, path: Option<String>, config: &Config, overrides: &Params) -> ExitCode {
+    let path = path.unwrap_or_else(|| {
+        let dir = config.input_day_dir(solution.year, solution.day);
+        match Path::new(&dir).is_dir() {
+            true => dir,
+            false => config.input_path(solution.year, solution.day),
+        }
+    });
+
+    let loaded =
+        list_inputs(&path).and_then(|paths| Ok((paths, AnswerStore::load(&config.answers_path)?)));
+    let (paths, store) = match loaded {
+        Ok(val) => val,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let (mut reports, locations) =
+        capture_panics(|| run_inputs(solution, &paths, &store, config, overrides));
+    // Panic messages go to the report rows instead of stderr, with the location the hook would have printed
+    for part_report in reports.iter_mut().flat_map(|report| &mut report.parts) {
+        if let Outcome::Panicked(message) = &mut part_report.outcome {
+            if let Some(location) = locations.get(message.as_str()) {
+                *message = format!("{message} at {location}");
+            }
+        }
+    }
+    print!("{}", format_report(&reports, config.output_format));
+
+    if !config.stats_path.is_empty() {
+        let entries = stats::run_entries(solution.year, solution.day, &reports, stats::now());
+        if let Err(err) = Ledger::append(&config.stats_path, &entries) {
+            eprintln!("Failed to record statistics: {err}");
+        }
+    }
+
+    // Flagged inputs => non-zero exit code, usable in scripts
+    match reports.iter().any(|report| report.is_flagged()) {
+        true => ExitCode::FAILURE,
+        false => ExitCode::SUCCESS,
+    }
+}
+
+fn submit(
+    solution: &Solution,
+    path: Option<String>,
+    config: &Config,
+    overrides: &Params,
+    args: &Args,
+) -> ExitCode {
+    let (year, day) = (solution.year, solution.day);
+    let part = match args.options.get("part").map(String::as_str) {
+        Some("1") => Part::One,
+        Some("2") => Part::Two,
+        _ => return usage_error(),
+    };
+    let path = path.unwrap_or_else(|| config.input_path(year, day));
+
+    let answer = match args.options.get("answer") {
+        Some(answer) => Ok(answer.clone()),
+        None => read_input(&path).and_then(|input| {
+            let params = solution.params_for_input(&path, &config.params(year, day), overrides)?;
+            match solution.solve(part, &input, &params) {
+                registry::Outcome::Answer(answer) => Ok(answer),
+                outcome => Err(Error::new(format!("No answer to submit - {outcome}"))),
+            }
+        }),
+    };
+    let recorded = answer.and_then(|answer| {
+        let store = AnswerStore::load(&config.answers_path)?;
+        if !config.stats_path.is_empty() {
+            let entry = Entry {
+                timestamp: stats::now(),
+                year,
+                day,
+                part,
+                input: input_name(&path),
+                event: Event::Attempt {
+                    answer: answer.clone(),
+                },
+            };
+            Ledger::append(&config.stats_path, &[entry])?;
+        }
+        Ok((answer, store))
+    });
+    let (answer, store) = match recorded {
+        Ok(val) => val,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match store.get(year, day, &input_name(&path), part) {
+        Some(accepted) if accepted == answer => {
+            println!("{part}: {answer} ✓ correct");
+            ExitCode::SUCCESS
+        }
+        Some(_) => {
+            println!("{part}: {answer} ✗ wrong");
+            ExitCode::FAILURE
+        }
+        None => {
+            println!(
+                "{part}: {answer} recorded, no accepted answer known. Submit it on https://adventofcode.com/{year}/day/{day} \
+                 and add the accepted answer to {}.",
+                config.answers_path
+            );
+            ExitCode::SUCCESS
+        }
+    }
+}
+
+fn stats(year: Option<u16>, config: &Config) -> ExitCode {
+    let loaded = Ledger::load(&config.stats_path)
+        .and_then(|ledger| Ok((ledger, AnswerStore::load(&config.answers_path)?)));
+    let (ledger, store) = match loaded {
+        Ok(val) => val,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut days = stats::summarize(&ledger, &store, |year, day| {
+        input_name(&config.input_path(year, day))
+    });
+    if let Some(year) = year {
+        days.retain(|day_stats| day_stats.year == year);
+    }
+    print!("{}", stats::format_stats(&days, stats::now()));
+
+    ExitCode::SUCCESS
+}
+
+fn leaderboard(year: Option<u16>, day: Option<u8>, config: &Config, args: &Args) -> ExitCode {
+    let Some(source) = args
+        .options
+        .get("source")
+        .or(config.leaderboard_source.as_ref())
+    else {
+        eprintln!("No leaderboard source - use --source or configure `leaderboard_source`.");
+        return ExitCode::FAILURE;
+    };
+
+    let Some(send_session) = args.option("send-session", false) else {
+        return usage_error();
+    };
+
+    let loaded = match send_session {
+        true => config.session_token(),
+        false => Ok(None),
+    }
+    .and_then(|token| Leaderboard::load_cached(source, token.as_deref(), &config.cache_dir));
+    let leaderboard = match loaded {
+        Ok(leaderboard) => leaderboard,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if year.is_some_and(|year| year != leaderboard.year) {
+        eprintln!(
+            "{source}: Leaderboard is for {}, not {}.",
+            leaderboard.year,
+            year.unwrap_or_default()
+        );
+        return ExitCode::FAILURE;
+    }
+
+    print!("{}", leaderboard.format_report(day));
+
+    ExitCode::SUCCESS
+}
+
+fn generate(year: u16, day: u8, args: &Args) -> ExitCode {
+    let Some(generator) = generate::find(year, day) else {
+        eprintln!("No generator registered for {year}/{day:02}.");
+        return ExitCode::FAILURE;
+    };
+
+    let (Some(size), Some(seed)) = (
+        args.option("size", generator.default_size),
+        args.option("seed", 0),
+    ) else {
+        return usage_error();
+    };
+
+    print!("{}", generator.generate(size, seed));
+
+    ExitCode::SUCCESS
+}
+
+fn fuzz(year: u16, day: u8, path: Option<String>, args: &Args) -> ExitCode {
+    let name = args.options.get("target").map(String::as_str);
+    let Some(target) = fuzz::find(year, day, name) else {
+        eprintln!(
+            "No fuzz target{} registered for {year}/{day:02}.",
+            name.map_or(String::new(), |name| format!(" '{name}'"))
+        );
+        return ExitCode::FAILURE;
+    };
+
+    let (Some(iterations), Some(seed)) =
+        (args.option("iterations", 100_000), args.option("seed", 0))
+    else {
+        return usage_error();
+    };
+
+    let mut corpus = target.seed_corpus();
+    if let Some(path) = path {
+        let inputs = list_inputs(&path)
+            .and_then(|paths| paths.iter().map(|path| read_input(path)).collect());
+        match inputs {
+            Ok(inputs) => corpus.extend::<Vec<String>>(inputs),
+            Err(err) => {
+                eprintln!("{err}");
+                return ExitCode::FAILURE;
+            }
+        }
+    }
+
+    // Panics are expected here, don't spam the output with the default hook
+    let (crash, _) = capture_panics(|| target.fuzz(&corpus, iterations, seed));
+    let Some(crash) = crash else {
+        println!(
+            "{}: no panic in {iterations} iterations ({} corpus inputs).",
+            target.name,
+            corpus.len()
+        );
+        return ExitCode::SUCCESS;
+    };
+
+    println!("{} panicked: {}", target.name, crash.message);
+    println!("Minimised input:\n{}", crash.input);
+    match fuzz::save_crash(&target, &crash) {
+        Ok(path) => println!("Saved to {path}"),
+        Err(err) => eprintln!("Failed to save the crash input: {err}"),
+    }
+
+    ExitCode::FAILURE
+}
+
+/// Run `f` with the panic hook replaced - panics are caught and reported by `f` itself, so instead of printing them
+/// to stderr the hook collects their locations by message. The hook is process-wide, no other thread may be expected
+/// to print its panics meanwhile.
+fn capture_panics<T>(f: impl FnOnce() -> T) -> (T, HashMap<String, String>) {
+    let locations = Arc::new(Mutex::new(HashMap::new()));
+    let captured = Arc::clone(&locations);
+
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let message = info.payload_as_str().unwrap_or("unknown panic").to_string();
+        if let (Some(location), Ok(mut locations)) = (info.location(), captured.lock()) {
+            locations.insert(message, location.to_string());
+        }
+    }));
+    let result = f();
+    panic::set_hook(default_hook);
+
+    let locations = mem::take(&mut *locations.lock().unwrap_or_else(PoisonError::into_inner));
+    (result, locations)
+}
+
+fn animate(
+    solution: &Solution,
+    path: Option<String>,
+    config: &Config,
+    overrides: &Params,
+    args: &Args,
+) -> ExitCode {
+    let (year, day) = (solution.year, solution.day);
+    let animation = match find_animation(solution, args) {
+        Ok(animation) => animation,
+        Err(exit_code) => return exit_code,
+    };
+
+    let (Some(start), Some(step), Some(frames), Some(scale), Some(delay)) = (
+        args.option("start", 0),
+        args.option("step", 1),
+        args.option("frames", 100),
+        args.option("scale", 4),
+        args.option("delay", 10),
+    ) else {
+        return usage_error();
+    };
+    let recording = Recording {
+        start,
+        step,
+        frames,
+    };
+    let output = args
+        .options
+        .get("output")
+        .cloned()
+        .unwrap_or_else(|| format!("animation_{year}_{day:02}.gif"));
+
+    let snapshots = read_animation_input(solution, path, config, overrides)
+        .and_then(|(input, params)| (animation.record)(&input, &params, &recording));
+    let snapshots = match snapshots {
+        Ok(snapshots) => snapshots,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let images: Vec<_> = snapshots
+        .iter()
+        .map(|snapshot| snapshot.to_image(scale, animation.colour))
+        .collect();
+
+    let saved = match output.ends_with(".gif") {
+        true => image::save_gif(&images, delay, &output),
+        false => {
+            image::save_png_sequence(&images, &output, &format!("{year}_{day:02}")).map(|_| ())
+        }
+    };
+    if let Err(err) = saved {
+        eprintln!("{err}");
+        return ExitCode::FAILURE;
+    }
+
+    let (first, last) = match (snapshots.first(), snapshots.last()) {
+        (Some(first), Some(last)) => (first.iteration, last.iteration),
+        _ => (0, 0),
+    };
+    println!(
+        "{} frames (iterations {first}..={last}) saved to {output}",
+        images.len()
+    );
+
+    ExitCode::SUCCESS
+}
+
+fn play(
+    solution: &Solution,
+    path: Option<String>,
+    config: &Config,
+    overrides: &Params,
+    args: &Args,
+) -> ExitCode {
+    let animation = match find_animation(solution, args) {
+        Ok(animation) => animation,
+        Err(exit_code) => return exit_code,
+    };
+    let (Some(start), Some(fps)) = (args.option("start", 0), args.option("fps", 10)) else {
+        return usage_error();
+    };
+
+    let played = read_animation_input(solution, path, config, overrides)
+        .and_then(|(input, params)| playback::play(animation, &input, &params, start, fps));
+    match played {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Animation of the `--part` option, exit code on failure.
+fn find_animation(
+    solution: &Solution,
+    args: &Args,
+) -> std::result::Result<&'static Animation, ExitCode> {
+    let part = match args.options.get("part").map(String::as_str) {
+        None => None,
+        Some("1") => Some(Part::One),
+        Some("2") => Some(Part::Two),
+        Some(_) => return Err(usage_error()),
+    };
+
+    animation::find(solution.year, solution.day, part).ok_or_else(|| {
+        eprintln!(
+            "No animation registered for {}/{:02}.",
+            solution.year, solution.day
+        );
+        ExitCode::FAILURE
+    })
+}
+
+/// Input file (the day's input by default) and its parameters.
+fn read_animation_input(
+    solution: &Solution,
+    path: Option<String>,
+    config: &Config,
+    overrides: &Params,
+) -> Result<(String, Params)> {
+    let (year, day) = (solution.year, solution.day);
+    let path = path.unwrap_or_else(|| config.input_path(year, day));
+
+    let input = read_input(&path)?;
+    let params = solution.params_for_input(&path, &config.params(year, day), overrides)?;
+
+    Ok((input, params))
+}
+
+fn usage_error() -> ExitCode {
+    eprintln!("{USAGE}");
+    ExitCode::FAILURE
+}
+
+/// Positional arguments, `--name value` options and `-v` verbosity flags.
+struct Args {
+    positional: Vec<String>,
+    options: HashMap<String, String>,
+    verbosity: u8,
+}
+
+impl Args {
+    fn parse(mut raw_args: impl Iterator<Item = String>) -> Option<Self> {
+        let mut positional = vec![];
+        let mut options = HashMap::new();
+        let mut verbosity = 0;
+
+        while let Some(arg) = raw_args.next() {
+            if let Some(arg_verbosity) = trace::parse_verbosity(&arg) {
+                verbosity = verbosity.max(arg_verbosity);
+                continue;
+            }
+
+            match arg.strip_prefix("--") {
+                Some(name) => {
+                    let value = raw_args.next()?;
+                    options.insert(name.to_string(), value);
+                }
+                None => positional.push(arg),
+            }
+        }
+
+        Some(Self {
+            positional,
+            options,
+            verbosity,
+        })
+    }
+
+    /// Parsed option value, `default` if not present, `None` if invalid.
+    fn option<T: std::str::FromStr>(&self, name: &str, default: T) -> Option<T> {
+        match self.options.get(name) {
+            Some(value) => value.parse().ok(),
+            None => Some(default),
+        }
+    }
+}
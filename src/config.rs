@@ -0,0 +1,332 @@
+//! Project configuration - `aoc.toml` in the working directory, overridden by `AOC_*` environment variables
+//! and command line options (in this order of precedence, lowest first).
+//!
+//! Supported is a small subset of TOML - `key = value` pairs with string or integer values and
+//! `[params.<year>.<day>]` tables with puzzle parameters (see `crate::params`), `#` starts a comment:
+//! ```toml
+//! default_year = 2024
+//! input_dir = "./resources"
+//! input_file = "{year}_{day}.txt"      # single input of the day, relative to input_dir
+//! input_day_dir = "{year}/{day}"       # directory with multiple inputs of the day, relative to input_dir
+//! cache_dir = "./.cache"               # fetched leaderboard exports
+//! answers_path = "./resources/answers.txt"
+//! session_token_path = "./.session"    # file with the adventofcode.com session cookie
+//! stats_path = "./resources/stats.txt" # personal statistics ledger, empty => not recorded
+//! leaderboard_source = "./leaderboard.json"  # private leaderboard export - file or `http://` URL
+//! threads = 4                          # inputs solved in parallel
+//! output_format = "table"              # `table` or `tsv`
+//!
+//! [params.2024.14]
+//! x_len = 101
+//! y_len = 103
+//! ```
+
+use std::{collections::HashMap, env, fs, io, str::FromStr};
+
+use crate::{
+    answers::ANSWERS_PATH,
+    error::{column_of, Error, Result},
+    input::{example_paths_in, RESOURCES_DIR},
+    params::Params,
+    stats::STATS_PATH,
+};
+
+pub const CONFIG_PATH: &str = "./aoc.toml";
+
+/// Environment variable with the config file path.
+pub const CONFIG_ENV: &str = "AOC_CONFIG";
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Year used when the command line omits it.
+    pub default_year: Option<u16>,
+    pub input_dir: String,
+    /// Template with `{year}` and `{day}` (zero-padded) placeholders.
+    pub input_file: String,
+    /// Template with `{year}` and `{day}` (zero-padded) placeholders.
+    pub input_day_dir: String,
+    /// Fetched data - private leaderboard exports (see `crate::leaderboard`).
+    pub cache_dir: String,
+    pub answers_path: String,
+    pub session_token_path: Option<String>,
+    /// Statistics ledger (see `crate::stats`), empty if disabled.
+    pub stats_path: String,
+    /// Private leaderboard JSON export (see `crate::leaderboard`).
+    pub leaderboard_source: Option<String>,
+    pub threads: usize,
+    pub output_format: OutputFormat,
+    /// Puzzle parameters by year and day - lower precedence than input sidecar files.
+    pub params: HashMap<(u16, u8), Params>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OutputFormat {
+    /// Aligned table for humans
+    Table,
+    /// Tab separated values for scripts
+    Tsv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = Error;
+
+    fn from_str(value: &str) -> Result<Self> {
+        match value {
+            "table" => Ok(OutputFormat::Table),
+            "tsv" => Ok(OutputFormat::Tsv),
+            _ => Err(Error::new(format!(
+                "Invalid output format '{value}', expected `table` or `tsv`."
+            ))),
+        }
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            default_year: None,
+            input_dir: RESOURCES_DIR.to_string(),
+            input_file: "{year}_{day}.txt".to_string(),
+            input_day_dir: "{year}/{day}".to_string(),
+            cache_dir: "./.cache".to_string(),
+            answers_path: ANSWERS_PATH.to_string(),
+            session_token_path: None,
+            stats_path: STATS_PATH.to_string(),
+            leaderboard_source: None,
+            threads: 1,
+            output_format: OutputFormat::Table,
+            params: HashMap::new(),
+        }
+    }
+}
+
+/// Setting names - keys in the config file, `AOC_<NAME>` environment variables and `--<name>` command line options
+/// (with `-` instead of `_`).
+const SETTINGS: [&str; 11] = [
+    "default_year",
+    "input_dir",
+    "input_file",
+    "input_day_dir",
+    "cache_dir",
+    "answers_path",
+    "session_token_path",
+    "stats_path",
+    "leaderboard_source",
+    "threads",
+    "output_format",
+];
+
+impl Config {
+    /// Load config file and apply environment overrides.
+    /// File is `path` if set, `AOC_CONFIG` otherwise, `./aoc.toml` as the last resort (missing => defaults).
+    pub fn load(path: Option<&str>) -> Result<Self> {
+        let mut config = match path
+            .map(str::to_string)
+            .or_else(|| env::var(CONFIG_ENV).ok())
+        {
+            Some(path) => Self::load_file(&path, true)?,
+            None => Self::load_file(CONFIG_PATH, false)?,
+        };
+
+        for name in SETTINGS {
+            let variable = format!("AOC_{}", name.to_uppercase());
+            if let Ok(value) = env::var(&variable) {
+                config
+                    .set(name, &value)
+                    .map_err(|err| Error::new(format!("{variable}: {}", err.description)))?;
+            }
+        }
+
+        Ok(config)
+    }
+
+    fn load_file(path: &str, required: bool) -> Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| err.with_file(path)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound && !required => Ok(Self::default()),
+            Err(err) => Err(Error::new(format!("Failed to read file: {err}")).with_file(path)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut config = Self::default();
+        // Current `[params.<year>.<day>]` table, `None` for top level
+        let mut table: Option<(u16, u8)> = None;
+
+        for (i, line) in content.lines().enumerate() {
+            let line_num = i + 1;
+            let line = strip_comment(line);
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if let Some(header) = line.trim().strip_prefix('[') {
+                let key = header
+                    .strip_suffix(']')
+                    .and_then(|header| header.trim().strip_prefix("params."))
+                    .and_then(|key| key.split_once('.'))
+                    .and_then(|(year, day)| Some((year.parse().ok()?, day.parse().ok()?)));
+
+                match key {
+                    Some(key) => table = Some(key),
+                    None => {
+                        return Err(Error::at_line(
+                            line_num,
+                            "Expected `[params.<year>.<day>]` table.",
+                        ))
+                    }
+                }
+                continue;
+            }
+
+            let (name, raw_value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::at_line(line_num, "Expected `key = value`."))?;
+            let name = name.trim();
+            let value = parse_value(raw_value.trim()).ok_or_else(|| {
+                Error::at(
+                    line_num,
+                    column_of(line, raw_value.trim_start()),
+                    "Expected string (in double quotes) or integer value.",
+                )
+            })?;
+
+            let result = match table {
+                Some(key) => {
+                    config.params.entry(key).or_default().set(name, &value);
+                    Ok(())
+                }
+                None => config.set(name, &value),
+            };
+            result.map_err(|err| Error::at(line_num, column_of(line, name), err.description))?;
+        }
+
+        Ok(config)
+    }
+
+    /// Set value of a setting by its name (see `SETTINGS`).
+    pub fn set(&mut self, name: &str, value: &str) -> Result<()> {
+        let invalid_number = || {
+            Error::new(format!(
+                "Invalid value '{value}' of '{name}', number expected."
+            ))
+        };
+
+        match name {
+            "default_year" => {
+                self.default_year = Some(value.parse().map_err(|_| invalid_number())?)
+            }
+            "input_dir" => self.input_dir = value.to_string(),
+            "input_file" => self.input_file = value.to_string(),
+            "input_day_dir" => self.input_day_dir = value.to_string(),
+            "cache_dir" => self.cache_dir = value.to_string(),
+            "answers_path" => self.answers_path = value.to_string(),
+            "session_token_path" => self.session_token_path = Some(value.to_string()),
+            "stats_path" => self.stats_path = value.to_string(),
+            "leaderboard_source" => self.leaderboard_source = Some(value.to_string()),
+            "threads" => match value.parse() {
+                Ok(threads) if threads > 0 => self.threads = threads,
+                _ => return Err(invalid_number()),
+            },
+            "output_format" => self.output_format = value.parse()?,
+            _ => {
+                return Err(Error::new(format!(
+                    "Unknown setting '{name}', expected one of: {}.",
+                    SETTINGS.join(", ")
+                )))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Apply command line options `--<name> <value>` of known settings (e.g. `--input-dir`).
+    pub fn apply_options(&mut self, options: &HashMap<String, String>) -> Result<()> {
+        for name in SETTINGS {
+            if let Some(value) = options.get(&name.replace('_', "-")) {
+                self.set(name, value)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Path to the puzzle input of the day, e.g. `./resources/2024_09.txt`.
+    pub fn input_path(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}",
+            self.input_dir,
+            fill_template(&self.input_file, year, day)
+        )
+    }
+
+    /// Directory with multiple inputs of the day, e.g. `./resources/2024/09`.
+    pub fn input_day_dir(&self, year: u16, day: u8) -> String {
+        format!(
+            "{}/{}",
+            self.input_dir,
+            fill_template(&self.input_day_dir, year, day)
+        )
+    }
+
+    pub fn example_paths(&self, year: u16, day: u8) -> Vec<String> {
+        example_paths_in(&self.input_dir, year, day)
+    }
+
+    /// Session cookie for adventofcode.com from `session_token_path`, `None` if not configured.
+    pub fn session_token(&self) -> Result<Option<String>> {
+        let Some(path) = &self.session_token_path else {
+            return Ok(None);
+        };
+
+        match fs::read_to_string(path) {
+            Ok(token) => Ok(Some(token.trim().to_string())),
+            Err(err) => Err(Error::new(format!("Failed to read file: {err}")).with_file(path)),
+        }
+    }
+
+    /// Parameters of the day from the config file.
+    pub fn params(&self, year: u16, day: u8) -> Params {
+        self.params.get(&(year, day)).cloned().unwrap_or_default()
+    }
+}
+
+fn fill_template(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day}", &format!("{day:02}"))
+}
+
+/// Strip `#` comment - outside of a double quoted string.
+fn strip_comment(line: &str) -> &str {
+    let mut in_string = false;
+
+    for (i, character) in line.char_indices() {
+        match character {
+            '"' => in_string = !in_string,
+            '#' if !in_string => return &line[..i],
+            _ => (),
+        }
+    }
+
+    line
+}
+
+/// String in double quotes (no escape sequences) or integer.
+fn parse_value(raw_value: &str) -> Option<String> {
+    match raw_value.strip_prefix('"') {
+        Some(rest) => {
+            let value = rest.strip_suffix('"')?;
+            (!value.contains('"')).then(|| value.to_string())
+        }
+        None => {
+            let digits = raw_value
+                .strip_prefix('-')
+                .unwrap_or(raw_value)
+                .replace('_', "");
+            (!digits.is_empty() && digits.chars().all(|character| character.is_ascii_digit()))
+                .then(|| raw_value.replace('_', ""))
+        }
+    }
+}
@@ -0,0 +1,104 @@
+//! Cycle detection of deterministic state machines - `step` maps a state to the next one, `None` ends the run
+//! (no cycle). `step` must be deterministic, Floyd's and Brent's algorithms call it repeatedly for the same state.
+//!
+//! State machine that neither halts nor cycles (infinite state space) makes the detection run forever.
+
+use std::{collections::HashMap, hash::Hash};
+
+/// States `start..start + length` (by index from the initial state 0) repeat forever.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+/// Every state is stored - `start + length` steps, fastest if the states are small.
+pub fn find_cycle_hashed<S: Clone + Hash + Eq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    let mut indexes: HashMap<S, usize> = HashMap::new();
+    let mut state = initial;
+
+    for index in 0.. {
+        let next = step(&state)?;
+        if let Some(start) = indexes.insert(state, index) {
+            return Some(Cycle {
+                start,
+                length: index - start,
+            });
+        }
+        state = next;
+    }
+
+    unreachable!("Loop ends by returning.")
+}
+
+/// Floyd's tortoise and hare - constant memory, about `3 * (start + length)` steps.
+pub fn find_cycle_floyd<S: Clone + PartialEq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    // Meet somewhere in the cycle - hare is twice as fast
+    let mut tortoise = step(&initial)?;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        hare = step(&hare)?;
+    }
+
+    // Distance of the meeting point from the initial state is a multiple of length => meet at the start
+    let mut start = 0;
+    let mut tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    let mut length = 1;
+    let mut hare = step(&tortoise)?;
+    while tortoise != hare {
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    Some(Cycle { start, length })
+}
+
+/// Brent's algorithm - constant memory, fewer steps than Floyd's (`length` is found directly).
+pub fn find_cycle_brent<S: Clone + PartialEq>(
+    initial: S,
+    mut step: impl FnMut(&S) -> Option<S>,
+) -> Option<Cycle> {
+    // Tortoise teleports to the hare at powers of two until the hare catches it
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial)?;
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare)?;
+        length += 1;
+    }
+
+    // Hare `length` steps ahead => both meet at the start
+    let mut start = 0;
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare)?;
+    }
+    while tortoise != hare {
+        tortoise = step(&tortoise)?;
+        hare = step(&hare)?;
+        start += 1;
+    }
+
+    Some(Cycle { start, length })
+}
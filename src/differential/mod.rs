@@ -0,0 +1,158 @@
+//! Differential testing - compare a simple reference implementation with the (optimised) registered solution
+//! on many generated inputs (with default parameters). The first mismatching input is minimised to make debugging easier.
+
+pub mod y2024;
+
+use std::fmt;
+
+use crate::{
+    generate::Generator,
+    params::Params,
+    registry::{self, Outcome, Part, PartSolver, Solution},
+};
+
+/// Reference implementation of one puzzle part.
+pub struct Reference {
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    pub solve: PartSolver,
+}
+
+impl Reference {
+    fn solution(&self) -> &'static Solution {
+        registry::find(self.year, self.day).expect("Reference of an unregistered solution.")
+    }
+
+    /// Run the reference with the same panic handling as registered solutions.
+    fn run(&self, input: &str) -> Outcome {
+        let reference = Solution {
+            part_one: self.solve,
+            part_two: self.solve,
+            ..*self.solution()
+        };
+
+        reference.solve(self.part, input, &Params::default())
+    }
+}
+
+pub struct Mismatch {
+    pub size: usize,
+    pub seed: u64,
+    /// Minimised input
+    pub input: String,
+    pub reference: Outcome,
+    pub solution: Outcome,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Mismatch for generated input (size {}, seed {}), minimised input:",
+            self.size, self.seed
+        )?;
+        writeln!(f, "{}", self.input)?;
+        writeln!(f, "Reference: {}", self.reference)?;
+        write!(f, "Solution:  {}", self.solution)
+    }
+}
+
+/// All registered reference implementations.
+pub fn references() -> impl Iterator<Item = &'static Reference> {
+    y2024::REFERENCES.iter()
+}
+
+/// Run reference and solution on generated inputs of each size and seed, return the first (minimised) mismatch.
+pub fn find_mismatch(
+    reference: &Reference,
+    generator: &Generator,
+    sizes: &[usize],
+    seeds: &[u64],
+) -> Option<Mismatch> {
+    for &size in sizes {
+        for &seed in seeds {
+            let input = generator.generate(size, seed);
+
+            if !is_mismatch(reference, &input) {
+                continue;
+            }
+
+            let input = minimize(&input, |candidate| is_mismatch(reference, candidate));
+
+            return Some(Mismatch {
+                size,
+                seed,
+                reference: reference.run(&input),
+                solution: reference
+                    .solution()
+                    .solve(reference.part, &input, &Params::default()),
+                input,
+            });
+        }
+    }
+
+    None
+}
+
+/// Input is a mismatch if the reference accepts it (it is valid) and the solution disagrees.
+fn is_mismatch(reference: &Reference, input: &str) -> bool {
+    match reference.run(input) {
+        Outcome::Answer(expected) => {
+            let outcome = reference
+                .solution()
+                .solve(reference.part, input, &Params::default());
+            outcome != Outcome::Answer(expected)
+        }
+        _ => false,
+    }
+}
+
+/// Greedily remove chunks of lines, then single characters, while the input still fails.
+pub fn minimize(input: &str, still_fails: impl Fn(&str) -> bool) -> String {
+    let mut lines: Vec<String> = input.lines().map(|line| line.to_string()).collect();
+
+    // Lines - halve the chunk size until single lines
+    let mut chunk_size = lines.len().max(1);
+    loop {
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + chunk_size).min(lines.len());
+            let mut candidate = lines.clone();
+            candidate.drain(start..end);
+
+            if still_fails(&candidate.join("\n")) {
+                lines = candidate;
+            } else {
+                start += chunk_size;
+            }
+        }
+
+        if chunk_size == 1 {
+            break;
+        }
+        chunk_size /= 2;
+    }
+
+    // Characters of each line
+    for line_index in 0..lines.len() {
+        let mut char_index = 0;
+        while char_index < lines[line_index].chars().count() {
+            let mut candidate = lines.clone();
+            let byte_index = candidate[line_index]
+                .char_indices()
+                .nth(char_index)
+                .map(|(i, _)| i)
+                .unwrap();
+            candidate[line_index].remove(byte_index);
+
+            if still_fails(&candidate.join("\n")) {
+                lines = candidate;
+            } else {
+                char_index += 1;
+            }
+        }
+    }
+
+    lines.join("\n")
+}
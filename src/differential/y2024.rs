@@ -0,0 +1,361 @@
+//! Straightforward reference implementations, written directly from the puzzle statements without any optimisations.
+
+use std::collections::{HashSet, VecDeque};
+
+use crate::{
+    error::{Error, Result},
+    parse_rows,
+    registry::Part,
+};
+
+use super::Reference;
+
+pub const REFERENCES: [Reference; 8] = [
+    Reference {
+        year: 2024,
+        day: 2,
+        part: Part::One,
+        solve: |input, _| day02_safe_report_count(input, false).map(|answer| answer.to_string()),
+    },
+    Reference {
+        year: 2024,
+        day: 2,
+        part: Part::Two,
+        solve: |input, _| day02_safe_report_count(input, true).map(|answer| answer.to_string()),
+    },
+    Reference {
+        year: 2024,
+        day: 6,
+        part: Part::One,
+        solve: |input, _| day06_visited_count(input).map(|answer| answer.to_string()),
+    },
+    Reference {
+        year: 2024,
+        day: 6,
+        part: Part::Two,
+        solve: |input, _| day06_cycle_count(input).map(|answer| answer.to_string()),
+    },
+    Reference {
+        year: 2024,
+        day: 9,
+        part: Part::One,
+        solve: |input, _| day09_checksum_by_block(input).map(|answer| answer.to_string()),
+    },
+    Reference {
+        year: 2024,
+        day: 9,
+        part: Part::Two,
+        solve: |input, _| day09_checksum_by_file(input).map(|answer| answer.to_string()),
+    },
+    Reference {
+        year: 2024,
+        day: 15,
+        part: Part::One,
+        solve: |input, _| day15_gps_sum(input, false).map(|answer| answer.to_string()),
+    },
+    Reference {
+        year: 2024,
+        day: 15,
+        part: Part::Two,
+        solve: |input, _| day15_gps_sum(input, true).map(|answer| answer.to_string()),
+    },
+];
+
+/// Try removing every level one by one.
+fn day02_safe_report_count(input: &str, dampener: bool) -> Result<usize> {
+    let rows: Vec<Vec<i32>> = parse_rows(input)?;
+
+    // Reports with 0 or 1 levels count as unsafe - same as the solution
+    let is_safe = |levels: &[i32]| {
+        let step_in = |range: std::ops::RangeInclusive<i32>| {
+            levels
+                .windows(2)
+                .all(|pair| range.contains(&(pair[1] - pair[0])))
+        };
+
+        levels.len() >= 2 && (step_in(1..=3) || step_in(-3..=-1))
+    };
+
+    let safe_count = rows
+        .iter()
+        .filter(|row| {
+            if row.len() <= 1 {
+                return false;
+            }
+
+            is_safe(row)
+                || (dampener
+                    && (0..row.len()).any(|i| {
+                        let mut levels = (*row).clone();
+                        levels.remove(i);
+                        is_safe(&levels)
+                    }))
+        })
+        .count();
+
+    Ok(safe_count)
+}
+
+fn day06_visited_count(input: &str) -> Result<usize> {
+    let (grid, guard) = day06_parse(input)?;
+
+    let visited = day06_walk(&grid, guard, None)
+        .ok_or_else(|| Error::new("First path is already cyclic."))?;
+
+    Ok(visited.len())
+}
+
+/// Place an obstacle on every empty point and walk the whole path again.
+fn day06_cycle_count(input: &str) -> Result<usize> {
+    let (grid, guard) = day06_parse(input)?;
+
+    if day06_walk(&grid, guard, None).is_none() {
+        return Err(Error::new("First path is already cyclic."));
+    }
+
+    let mut cycle_count = 0;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, point) in row.iter().enumerate() {
+            if *point == '.' && day06_walk(&grid, guard, Some((x, y))).is_none() {
+                cycle_count += 1;
+            }
+        }
+    }
+
+    Ok(cycle_count)
+}
+
+/// Returns visited points, `None` if the path is cyclic.
+fn day06_walk(
+    grid: &[Vec<char>],
+    (mut x, mut y): (usize, usize),
+    obstacle: Option<(usize, usize)>,
+) -> Option<HashSet<(usize, usize)>> {
+    let mut direction = (0, -1);
+    let mut states = HashSet::new();
+
+    loop {
+        if !states.insert((x, y, direction)) {
+            return None;
+        }
+
+        let next = (
+            x.checked_add_signed(direction.0),
+            y.checked_add_signed(direction.1),
+        );
+        let (next_x, next_y) = match next {
+            (Some(next_x), Some(next_y)) => (next_x, next_y),
+            _ => break,
+        };
+
+        match grid.get(next_y).and_then(|row| row.get(next_x)) {
+            None => break,
+            Some('#') => direction = (-direction.1, direction.0),
+            Some(_) if obstacle == Some((next_x, next_y)) => {
+                direction = (-direction.1, direction.0)
+            }
+            Some(_) => (x, y) = (next_x, next_y),
+        }
+    }
+
+    Some(states.into_iter().map(|(x, y, _)| (x, y)).collect())
+}
+
+fn day06_parse(input: &str) -> Result<(CharGrid, (usize, usize))> {
+    let grid: CharGrid = input.lines().map(|line| line.chars().collect()).collect();
+    let mut guard = None;
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, point) in row.iter().enumerate() {
+            match point {
+                '.' | '#' => (),
+                '^' if guard.is_none() => guard = Some((x, y)),
+                _ => return Err(Error::at(y + 1, x + 1, "Invalid point.")),
+            }
+        }
+    }
+
+    let guard = guard.ok_or_else(|| Error::new("No guard found."))?;
+
+    Ok((grid, guard))
+}
+
+/// Move the last file block to the first free block until there is no gap.
+fn day09_checksum_by_block(input: &str) -> Result<usize> {
+    let mut disk = day09_parse(input)?;
+
+    while let (Some(free), Some(last)) = (
+        disk.iter().position(|block| block.is_none()),
+        disk.iter().rposition(|block| block.is_some()),
+    ) {
+        if free > last {
+            break;
+        }
+        disk.swap(free, last);
+    }
+
+    Ok(day09_checksum(&disk))
+}
+
+/// Search the whole disk from the left for every file.
+fn day09_checksum_by_file(input: &str) -> Result<usize> {
+    let mut disk = day09_parse(input)?;
+    let max_id = disk.iter().flatten().max().copied().unwrap_or(0);
+
+    for id in (0..=max_id).rev() {
+        let start = match disk.iter().position(|block| *block == Some(id)) {
+            Some(start) => start,
+            None => continue, // empty file
+        };
+        let length = disk[start..]
+            .iter()
+            .take_while(|block| **block == Some(id))
+            .count();
+
+        let free_start = (0..start).find(|&free_start| {
+            free_start + length <= start
+                && disk[free_start..free_start + length]
+                    .iter()
+                    .all(|block| block.is_none())
+        });
+
+        if let Some(free_start) = free_start {
+            for i in 0..length {
+                disk.swap(free_start + i, start + i);
+            }
+        }
+    }
+
+    Ok(day09_checksum(&disk))
+}
+
+fn day09_checksum(disk: &[Option<usize>]) -> usize {
+    disk.iter()
+        .enumerate()
+        .map(|(i, block)| i * block.unwrap_or(0))
+        .sum()
+}
+
+fn day09_parse(input: &str) -> Result<Vec<Option<usize>>> {
+    let mut disk = vec![];
+
+    for (i, character) in input.trim_end().chars().enumerate() {
+        let count = character
+            .to_digit(10)
+            .ok_or_else(|| Error::at(1, i + 1, "Digit expected."))?;
+
+        let block = match i % 2 {
+            0 => Some(i / 2),
+            _ => None,
+        };
+        disk.extend((0..count).map(|_| block));
+    }
+
+    Ok(disk)
+}
+
+/// Collect every point pushed by the robot with a breadth first search, then move them all at once.
+fn day15_gps_sum(input: &str, enlarged: bool) -> Result<usize> {
+    let (grid, moves) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let mut grid: Vec<Vec<char>> = grid
+        .lines()
+        .map(|line| {
+            line.chars()
+                .flat_map(|point| match (enlarged, point) {
+                    (false, _) => vec![point],
+                    (true, 'O') => vec!['[', ']'],
+                    (true, '@') => vec!['@', '.'],
+                    (true, _) => vec![point, point],
+                })
+                .collect()
+        })
+        .collect();
+
+    // Validate grid
+    let width = grid.first().map_or(0, |row| row.len());
+    let mut robot = None;
+    for (y, row) in grid.iter().enumerate() {
+        if row.len() != width {
+            return Err(Error::at_line(y + 1, "Grid must be rectangular."));
+        }
+
+        for (x, point) in row.iter().enumerate() {
+            let is_border = y == 0 || y == grid.len() - 1 || x == 0 || x == width - 1;
+            match point {
+                '#' => (),
+                _ if is_border => return Err(Error::at_line(y + 1, "Missing wall.")),
+                '.' | 'O' | '[' | ']' => (),
+                '@' if robot.is_none() => robot = Some((x, y)),
+                _ => return Err(Error::at_line(y + 1, "Invalid point.")),
+            }
+        }
+    }
+    let (mut robot_x, mut robot_y) = robot.ok_or_else(|| Error::new("No robot found."))?;
+
+    for instruction in moves.chars().filter(|character| *character != '\n') {
+        let (dx, dy): (isize, isize) = match instruction {
+            '^' => (0, -1),
+            'v' => (0, 1),
+            '<' => (-1, 0),
+            '>' => (1, 0),
+            _ => return Err(Error::new("Invalid move.")),
+        };
+        let next = |(x, y): (usize, usize)| {
+            (
+                x.checked_add_signed(dx).unwrap(),
+                y.checked_add_signed(dy).unwrap(),
+            )
+        };
+
+        // Points to move, starting with the robot
+        let mut to_move = vec![(robot_x, robot_y)];
+        let mut queue = VecDeque::from([(robot_x, robot_y)]);
+        let mut is_blocked = false;
+
+        while let Some(point) = queue.pop_front() {
+            let (x, y) = next(point);
+            let mut pushed = match grid[y][x] {
+                '#' => {
+                    is_blocked = true;
+                    break;
+                }
+                'O' => vec![(x, y)],
+                '[' if dy != 0 => vec![(x, y), (x + 1, y)],
+                ']' if dy != 0 => vec![(x, y), (x - 1, y)],
+                '[' | ']' => vec![(x, y)],
+                _ => vec![],
+            };
+            pushed.retain(|pushed_point| !to_move.contains(pushed_point));
+            to_move.extend(pushed.iter().copied());
+            queue.extend(pushed);
+        }
+
+        if is_blocked {
+            continue;
+        }
+
+        let points: Vec<char> = to_move.iter().map(|&(x, y)| grid[y][x]).collect();
+        for &(x, y) in &to_move {
+            grid[y][x] = '.';
+        }
+        for (&point, character) in to_move.iter().zip(points) {
+            let (x, y) = next(point);
+            grid[y][x] = character;
+        }
+        (robot_x, robot_y) = next((robot_x, robot_y));
+    }
+
+    let mut gps_sum = 0;
+    for (y, row) in grid.iter().enumerate() {
+        for (x, point) in row.iter().enumerate() {
+            if *point == 'O' || *point == '[' {
+                gps_sum += 100 * y + x;
+            }
+        }
+    }
+
+    Ok(gps_sum)
+}
+
+type CharGrid = Vec<Vec<char>>;
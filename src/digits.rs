@@ -0,0 +1,121 @@
+//! Decimal digit manipulation of unsigned integers without going through strings.
+//!
+//! Digit indexes count from the most significant digit, like string indexes of the number's decimal representation.
+//! Methods that can overflow or get an invalid index panic, their `checked_` variants return `None` instead.
+
+/// Decimal digit operations, implemented for all unsigned integer types.
+pub trait Digits: Sized {
+    /// Number of decimal digits, 0 has one digit.
+    fn digit_count(self) -> u32;
+
+    /// Split into the first `index` digits and the rest, e.g. 123456 at 2 is (12, 3456). Leading zeros of the right
+    /// part are lost (1005 at 2 is (10, 5)).
+    ///
+    /// Panics if `index` is greater than the digit count.
+    fn split_at_digit(self, index: u32) -> (Self, Self) {
+        self.checked_split_at_digit(index)
+            .expect("Split index must not exceed the digit count.")
+    }
+
+    fn checked_split_at_digit(self, index: u32) -> Option<(Self, Self)>;
+
+    /// Digits of `other` appended, e.g. 12 and 345 is 12345.
+    ///
+    /// Panics on overflow.
+    fn concat(self, other: Self) -> Self {
+        self.checked_concat(other)
+            .expect("Digit concatenation overflowed.")
+    }
+
+    fn checked_concat(self, other: Self) -> Option<Self>;
+
+    /// Digits in reverse order, trailing zeros are lost (120 is 21).
+    ///
+    /// Panics on overflow.
+    fn reverse_digits(self) -> Self {
+        self.checked_reverse_digits()
+            .expect("Digit reversal overflowed.")
+    }
+
+    fn checked_reverse_digits(self) -> Option<Self>;
+
+    /// Digits from the most significant one.
+    fn digits(self) -> DigitIter<Self>;
+}
+
+/// Iterator over the digits of a number, see `Digits::digits`.
+#[derive(Clone, Debug)]
+pub struct DigitIter<T> {
+    value: T,
+    /// Power of ten of the next digit, 0 when finished
+    divisor: T,
+}
+
+macro_rules! impl_digits {
+    ($($t:ty),*) => {
+        $(
+            impl Digits for $t {
+                fn digit_count(self) -> u32 {
+                    self.checked_ilog10().unwrap_or(0) + 1
+                }
+
+                fn checked_split_at_digit(self, index: u32) -> Option<(Self, Self)> {
+                    let right_length = self.digit_count().checked_sub(index)?;
+                    // 10^digit_count may overflow, splitting at 0 takes everything
+                    match (10 as $t).checked_pow(right_length) {
+                        Some(divisor) if index > 0 => Some((self / divisor, self % divisor)),
+                        _ => Some((0, self)),
+                    }
+                }
+
+                fn checked_concat(self, other: Self) -> Option<Self> {
+                    self.checked_mul((10 as $t).checked_pow(other.digit_count())?)?
+                        .checked_add(other)
+                }
+
+                fn checked_reverse_digits(self) -> Option<Self> {
+                    let mut value = self;
+                    let mut reversed: $t = 0;
+                    while value > 0 {
+                        reversed = reversed.checked_mul(10)?.checked_add(value % 10)?;
+                        value /= 10;
+                    }
+
+                    Some(reversed)
+                }
+
+                fn digits(self) -> DigitIter<Self> {
+                    DigitIter {
+                        value: self,
+                        divisor: (10 as $t).pow(self.digit_count() - 1),
+                    }
+                }
+            }
+
+            impl Iterator for DigitIter<$t> {
+                type Item = u8;
+
+                fn next(&mut self) -> Option<u8> {
+                    if self.divisor == 0 {
+                        return None;
+                    }
+
+                    let digit = self.value / self.divisor;
+                    self.value %= self.divisor;
+                    self.divisor /= 10;
+
+                    Some(digit as u8)
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let remaining = self.divisor.checked_ilog10().map_or(0, |log| log as usize + 1);
+                    (remaining, Some(remaining))
+                }
+            }
+
+            impl ExactSizeIterator for DigitIter<$t> {}
+        )*
+    };
+}
+
+impl_digits!(u8, u16, u32, u64, u128, usize);
@@ -0,0 +1,100 @@
+//! Crate-wide error type.
+//! Malformed input is reported as an `Error` pointing to the offending place instead of panicking.
+
+use std::fmt;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Clone, PartialEq)]
+pub struct Error {
+    pub file: Option<String>,
+    /// 1-based line number
+    pub line: Option<usize>,
+    /// 1-based column number (in characters)
+    pub column: Option<usize>,
+    pub description: String,
+}
+
+impl Error {
+    pub fn new(description: impl Into<String>) -> Self {
+        Self {
+            file: None,
+            line: None,
+            column: None,
+            description: description.into(),
+        }
+    }
+
+    pub fn at_line(line: usize, description: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            ..Self::new(description)
+        }
+    }
+
+    pub fn at(line: usize, column: usize, description: impl Into<String>) -> Self {
+        Self {
+            line: Some(line),
+            column: Some(column),
+            ..Self::new(description)
+        }
+    }
+
+    /// Move an error positioned within a single line (at line 1) to `line`.
+    pub fn with_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    pub fn with_file(mut self, file: &str) -> Self {
+        self.file = Some(file.to_string());
+        self
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // `file:line:column: description` - parts that are not known are omitted.
+        if let Some(file) = &self.file {
+            write!(f, "{file}:")?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{line}:")?;
+        }
+        if let Some(column) = self.column {
+            write!(f, "{column}:")?;
+        }
+        if self.file.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+
+        write!(f, "{}", self.description)
+    }
+}
+
+/// Same as `Display` so that `main() -> Result<(), Error>` prints a readable diagnostic.
+impl fmt::Debug for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Attach file path to an error result.
+pub trait InFile {
+    fn in_file(self, file: &str) -> Self;
+}
+
+impl<T> InFile for Result<T> {
+    fn in_file(self, file: &str) -> Self {
+        self.map_err(|err| err.with_file(file))
+    }
+}
+
+/// 1-based column of `part` within `line`. `part` must be a subslice of `line`.
+pub fn column_of(line: &str, part: &str) -> usize {
+    let offset = (part.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+
+    line[..offset.min(line.len())].chars().count() + 1
+}
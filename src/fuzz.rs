@@ -0,0 +1,306 @@
+//! Fuzzing of the input parsers and solvers - they must return an `Err` for invalid input, never panic.
+//! Inputs are random mutations of a corpus seeded from the puzzle input and example files (libFuzzer style, but std only).
+
+use std::{
+    fs,
+    panic::{self, AssertUnwindSafe},
+};
+
+use crate::{
+    differential::minimize,
+    generate::Rng,
+    input::{example_paths, input_path, read_input},
+    params::Params,
+    parse_columns, parse_rows,
+    registry::{self, Outcome, PARTS},
+    y2024,
+};
+
+/// Parser (or another entry point) of one day's input, or the whole solution.
+#[derive(Clone, Copy)]
+pub struct Target {
+    pub year: u16,
+    pub day: u8,
+    pub name: &'static str,
+    pub run: Run,
+}
+
+#[derive(Clone, Copy)]
+pub enum Run {
+    /// Run the entry point, the result is ignored - only panics are reported.
+    Function(fn(&str)),
+    /// Both parts by `registry::Solution::solve` with default parameters - reported are `Outcome::Panicked` results.
+    Solve,
+}
+
+/// Day specific targets, see `targets()` for all of them.
+pub const PARSERS: [Target; 15] = [
+    Target {
+        year: 2024,
+        day: 1,
+        name: "parse_columns",
+        run: Run::Function(|input| {
+            let _ = parse_columns::<i32, 2>(input);
+        }),
+    },
+    Target {
+        year: 2024,
+        day: 2,
+        name: "parse_rows",
+        run: Run::Function(|input| {
+            let _ = parse_rows::<i32>(input);
+        }),
+    },
+    Target {
+        year: 2024,
+        day: 3,
+        name: "expressions",
+        run: Run::Function(y2024::day03::fuzz_expressions),
+    },
+    Target {
+        year: 2024,
+        day: 4,
+        name: "grid_search",
+        run: Run::Function(y2024::day04::fuzz_grid_search),
+    },
+    Target {
+        year: 2024,
+        day: 5,
+        name: "parse_file",
+        run: Run::Function(y2024::day05::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 6,
+        name: "parse_file",
+        run: Run::Function(y2024::day06::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 7,
+        name: "parse_file",
+        run: Run::Function(y2024::day07::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 8,
+        name: "parse_file",
+        run: Run::Function(y2024::day08::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 9,
+        name: "parse_file",
+        run: Run::Function(y2024::day09::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 10,
+        name: "parse_file",
+        run: Run::Function(y2024::day10::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 11,
+        name: "parse_file",
+        run: Run::Function(y2024::day11::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 12,
+        name: "parse_file",
+        run: Run::Function(y2024::day12::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 13,
+        name: "parse_file",
+        run: Run::Function(y2024::day13::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 14,
+        name: "parse_file",
+        run: Run::Function(y2024::day14::fuzz_parse_file),
+    },
+    Target {
+        year: 2024,
+        day: 15,
+        name: "parse_file",
+        run: Run::Function(y2024::day15::fuzz_parse_file),
+    },
+];
+
+/// Day specific targets followed by a `solve` target of each registered solution.
+pub fn targets() -> impl Iterator<Item = Target> {
+    let solve_targets = registry::solutions().map(|solution| Target {
+        year: solution.year,
+        day: solution.day,
+        name: "solve",
+        run: Run::Solve,
+    });
+
+    PARSERS.into_iter().chain(solve_targets)
+}
+
+/// Target of the day by name, the first one (the day's parser) if `name` is not set.
+pub fn find(year: u16, day: u8, name: Option<&str>) -> Option<Target> {
+    targets().find(|target| {
+        target.year == year && target.day == day && name.is_none_or(|name| target.name == name)
+    })
+}
+
+/// Input which made the parser panic.
+pub struct Crash {
+    /// Minimised input
+    pub input: String,
+    pub message: String,
+}
+
+impl Target {
+    /// Returns the panic message if the target panicked.
+    pub fn check(&self, input: &str) -> Option<String> {
+        let run = match self.run {
+            Run::Function(run) => run,
+            Run::Solve => return self.check_solve(input),
+        };
+
+        match panic::catch_unwind(AssertUnwindSafe(|| run(input))) {
+            Ok(()) => None,
+            Err(payload) => Some(
+                payload
+                    .downcast_ref::<&str>()
+                    .map(|message| message.to_string())
+                    .or_else(|| payload.downcast_ref::<String>().cloned())
+                    .unwrap_or_else(|| "unknown panic".to_string()),
+            ),
+        }
+    }
+
+    /// Panic message of the first part that panicked - `solve` catches the panics itself.
+    fn check_solve(&self, input: &str) -> Option<String> {
+        let solution = registry::find(self.year, self.day)?;
+
+        PARTS.into_iter().find_map(
+            |part| match solution.solve(part, input, &Params::default()) {
+                Outcome::Panicked(message) => Some(format!("{part}: {message}")),
+                Outcome::Answer(_) | Outcome::Failed(_) => None,
+            },
+        )
+    }
+
+    /// Puzzle input and example files of the day. Missing files are skipped, an empty input is always included.
+    /// Solve targets use just the examples - solving a mutated puzzle input takes too long per iteration.
+    pub fn seed_corpus(&self) -> Vec<String> {
+        let mut paths = example_paths(self.year, self.day);
+        if let Run::Function(_) = self.run {
+            paths.push(input_path(self.year, self.day));
+        }
+
+        let mut corpus = vec![String::new()];
+        corpus.extend(paths.iter().filter_map(|path| read_input(path).ok()));
+
+        corpus
+    }
+
+    /// Run the parser on `iterations` mutated corpus inputs, return the first (minimised) crash.
+    /// The panic hook is left alone - callers that don't want every caught panic on stderr silence it themselves.
+    pub fn fuzz(&self, corpus: &[String], iterations: usize, seed: u64) -> Option<Crash> {
+        let mut rng = Rng::new(seed);
+
+        for _ in 0..iterations {
+            let input = mutate(&mut rng, corpus);
+
+            if let Some(message) = self.check(&input) {
+                let input = minimize(&input, |candidate| self.check(candidate).is_some());
+                return Some(Crash { input, message });
+            }
+        }
+
+        None
+    }
+}
+
+/// Interesting fragments for parsers - separators, signs, overflowing and non-ASCII values.
+const DICTIONARY: [&str; 14] = [
+    "\n",
+    "\n\n",
+    " ",
+    ",",
+    ":",
+    "|",
+    "-",
+    "=",
+    "0",
+    "9",
+    "18446744073709551616",
+    "-9223372036854775809",
+    "é",
+    "\u{1F384}",
+];
+
+/// Apply a few random mutations to a random corpus entry.
+fn mutate(rng: &mut Rng, corpus: &[String]) -> String {
+    let mut chars: Vec<char> = rng.choose(corpus).chars().collect();
+
+    // Large inputs => mostly work with a small window to keep the mutations meaningful
+    if chars.len() > 200 && rng.chance(3, 4) {
+        let start = rng.index(chars.len() - 200);
+        chars = chars[start..start + 200].to_vec();
+    }
+
+    for _ in 0..=rng.index(4) {
+        let position = rng.index(chars.len() + 1);
+
+        match rng.index(6) {
+            // Insert dictionary fragment
+            0 => {
+                let fragment: &&str = rng.choose(&DICTIONARY);
+                chars.splice(position..position, fragment.chars());
+            }
+            // Replace character with a character from the input
+            1 if position < chars.len() => chars[position] = chars[rng.index(chars.len())],
+            // Remove range
+            2 if position < chars.len() => {
+                let end = position + rng.index((chars.len() - position).min(16)) + 1;
+                chars.drain(position..end);
+            }
+            // Duplicate range
+            3 if position < chars.len() => {
+                let end = position + rng.index((chars.len() - position).min(16)) + 1;
+                let range = chars[position..end].to_vec();
+                chars.splice(position..position, range);
+            }
+            // Truncate
+            4 => chars.truncate(position),
+            // Splice with another corpus entry
+            5 => {
+                let other: Vec<char> = rng.choose(corpus).chars().collect();
+                let start = rng.index(other.len() + 1);
+                chars.truncate(position);
+                chars.extend(&other[start..]);
+            }
+            _ => (),
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+/// Save the crash input to `fuzz/artifacts/<year>_<day>/`, returns the file path.
+pub fn save_crash(target: &Target, crash: &Crash) -> std::io::Result<String> {
+    let dir = format!("./fuzz/artifacts/{}_{:02}", target.year, target.day);
+    fs::create_dir_all(&dir)?;
+
+    let path = format!("{dir}/crash-{:016x}.txt", fnv1a(&crash.input));
+    fs::write(&path, &crash.input)?;
+
+    Ok(path)
+}
+
+/// Stable hash for artifact names.
+fn fnv1a(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
@@ -0,0 +1,83 @@
+//! Random puzzle input generators for stress testing and benchmarking.
+//! Generated inputs are valid (accepted by the solvers) and deterministic for the given seed.
+
+pub mod y2024;
+
+use std::ops::RangeInclusive;
+
+/// Generator of random input for one day.
+pub struct Generator {
+    pub year: u16,
+    pub day: u8,
+    /// Meaning of size is day specific (line count, grid side, ...), see the generator function.
+    pub default_size: usize,
+    pub generate: fn(&mut Rng, usize) -> String,
+}
+
+impl Generator {
+    pub fn generate(&self, size: usize, seed: u64) -> String {
+        (self.generate)(&mut Rng::new(seed), size)
+    }
+}
+
+/// All registered generators.
+pub fn generators() -> impl Iterator<Item = &'static Generator> {
+    y2024::GENERATORS.iter()
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Generator> {
+    generators().find(|generator| generator.year == year && generator.day == day)
+}
+
+/// Small deterministic pseudo-random number generator (SplitMix64).
+/// Not suitable for cryptography, good enough for puzzle inputs.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Random number in range (both ends inclusive).
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = (*range.start(), *range.end());
+        assert!(start <= end, "Empty range.");
+
+        let span = (end as i128 - start as i128 + 1) as u128;
+        (start as i128 + (self.next_u64() as u128 % span) as i128) as i64
+    }
+
+    /// Random index for a collection of length `len`.
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0, "Empty collection.");
+
+        (self.next_u64() % len as u64) as usize
+    }
+
+    /// `true` with probability `numerator / denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+
+    /// Fisher-Yates shuffle.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.index(i + 1));
+        }
+    }
+}
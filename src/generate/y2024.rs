@@ -0,0 +1,490 @@
+//! Input generators for https://adventofcode.com/2024.
+
+use std::fmt::Write;
+
+use super::{Generator, Rng};
+use crate::y2024::{day06, day14};
+
+const YEAR: u16 = 2024;
+
+pub const GENERATORS: [Generator; 15] = [
+    Generator {
+        year: YEAR,
+        day: 1,
+        default_size: 1000,
+        generate: day01,
+    },
+    Generator {
+        year: YEAR,
+        day: 2,
+        default_size: 1000,
+        generate: day02,
+    },
+    Generator {
+        year: YEAR,
+        day: 3,
+        default_size: 1000,
+        generate: day03,
+    },
+    Generator {
+        year: YEAR,
+        day: 4,
+        default_size: 140,
+        generate: day04,
+    },
+    Generator {
+        year: YEAR,
+        day: 5,
+        default_size: 200,
+        generate: day05,
+    },
+    Generator {
+        year: YEAR,
+        day: 6,
+        default_size: 130,
+        generate: day06,
+    },
+    Generator {
+        year: YEAR,
+        day: 7,
+        default_size: 850,
+        generate: day07,
+    },
+    Generator {
+        year: YEAR,
+        day: 8,
+        default_size: 50,
+        generate: day08,
+    },
+    Generator {
+        year: YEAR,
+        day: 9,
+        default_size: 19999,
+        generate: day09,
+    },
+    Generator {
+        year: YEAR,
+        day: 10,
+        default_size: 50,
+        generate: day10,
+    },
+    Generator {
+        year: YEAR,
+        day: 11,
+        default_size: 8,
+        generate: day11,
+    },
+    Generator {
+        year: YEAR,
+        day: 12,
+        default_size: 140,
+        generate: day12,
+    },
+    Generator {
+        year: YEAR,
+        day: 13,
+        default_size: 320,
+        generate: day13,
+    },
+    Generator {
+        year: YEAR,
+        day: 14,
+        default_size: 350,
+        generate: day14,
+    },
+    Generator {
+        year: YEAR,
+        day: 15,
+        default_size: 50,
+        generate: day15,
+    },
+];
+
+/// `size` lines with two location ids. Roughly half of the right ids repeat a left id.
+pub fn day01(rng: &mut Rng, size: usize) -> String {
+    let left: Vec<i64> = (0..size).map(|_| rng.range(10000..=99999)).collect();
+    let mut content = String::new();
+
+    for left_id in &left {
+        let right_id = match rng.chance(1, 2) {
+            true => *rng.choose(&left),
+            false => rng.range(10000..=99999),
+        };
+        writeln!(content, "{left_id}   {right_id}").unwrap();
+    }
+
+    content
+}
+
+/// `size` reports of 1-8 levels, mostly monotone with occasional bad steps.
+pub fn day02(rng: &mut Rng, size: usize) -> String {
+    let mut content = String::new();
+
+    for _ in 0..size {
+        let length = rng.range(1..=8);
+        let direction = if rng.chance(1, 2) { 1 } else { -1 };
+        let mut level = rng.range(1..=99);
+        let mut levels = vec![level];
+
+        for _ in 1..length {
+            let step = match rng.chance(1, 8) {
+                true => rng.range(-6..=6), // possibly unsafe step
+                false => direction * rng.range(1..=3),
+            };
+            level += step;
+            levels.push(level);
+        }
+
+        writeln!(content, "{}", join(&levels, " ")).unwrap();
+    }
+
+    content
+}
+
+/// Corrupted memory with `size` tokens - valid and invalid `mul`, `do()`, `don't()` and noise.
+pub fn day03(rng: &mut Rng, size: usize) -> String {
+    const NOISE: [&str; 8] = [
+        "%",
+        "*",
+        "mul[",
+        "mul(4*",
+        "mul ( 2 , 4 )",
+        "what()",
+        "do_not_",
+        "+mul(32,64]",
+    ];
+    let mut content = String::new();
+
+    for _ in 0..size {
+        match rng.range(0..=9) {
+            0 => content.push_str("do()"),
+            1 => content.push_str("don't()"),
+            2..=5 => write!(
+                content,
+                "mul({},{})",
+                rng.range(0..=999),
+                rng.range(0..=999)
+            )
+            .unwrap(),
+            _ => {
+                let noise: &&str = rng.choose(&NOISE);
+                content.push_str(noise)
+            }
+        }
+    }
+
+    content.push('\n');
+    content
+}
+
+/// `size` x `size` grid of `XMAS` letters.
+pub fn day04(rng: &mut Rng, size: usize) -> String {
+    grid(size, size, || *rng.choose(&['X', 'M', 'A', 'S']))
+}
+
+/// Consistent ordering rules and `size` updates, some of them already sorted.
+/// Rules cover all pairs of pages so the sorted order is unique.
+pub fn day05(rng: &mut Rng, size: usize) -> String {
+    let page_count = rng.range(5..=25) as usize;
+    let mut pages: Vec<i64> = (10..=99).collect();
+    rng.shuffle(&mut pages);
+    pages.truncate(page_count); // order of pages is the sorted order
+
+    let mut rules = vec![];
+    for i in 0..pages.len() {
+        for j in (i + 1)..pages.len() {
+            rules.push(format!("{}|{}", pages[i], pages[j]));
+        }
+    }
+    rng.shuffle(&mut rules);
+
+    let mut content = rules.join("\n");
+    content.push_str("\n\n");
+
+    for _ in 0..size {
+        let length = 2 * rng.index(page_count.div_ceil(2)) + 1; // odd
+        let mut indexes: Vec<usize> = (0..page_count).collect();
+        rng.shuffle(&mut indexes);
+        indexes.truncate(length);
+
+        if rng.chance(1, 3) {
+            indexes.sort();
+        }
+
+        let update: Vec<i64> = indexes.into_iter().map(|i| pages[i]).collect();
+        writeln!(content, "{}", join(&update, ",")).unwrap();
+    }
+
+    content
+}
+
+/// `size` x `size` lab with ~10% obstacles. The guard's first path is never cyclic.
+pub fn day06(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    loop {
+        let mut rows: Vec<Vec<char>> = (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| if rng.chance(1, 10) { '#' } else { '.' })
+                    .collect()
+            })
+            .collect();
+        rows[rng.index(size)][rng.index(size)] = '^';
+
+        let content = rows_to_string(&rows);
+
+        if day06::visited_count(&content).is_ok() {
+            return content;
+        }
+    }
+}
+
+/// `size` equations of 2-6 values. About half of them are solvable.
+pub fn day07(rng: &mut Rng, size: usize) -> String {
+    let mut content = String::new();
+
+    for _ in 0..size {
+        let count = rng.range(2..=6);
+        let values: Vec<i64> = (0..count).map(|_| rng.range(1..=999)).collect();
+
+        let result = match rng.chance(1, 2) {
+            true => values[1..]
+                .iter()
+                .fold(values[0], |acc, value| match rng.range(0..=2) {
+                    0 => acc + value,
+                    1 => acc * value,
+                    _ => format!("{acc}{value}").parse().unwrap(), // at most 18 digits - fits
+                }),
+            false => rng.range(1..=999_999_999),
+        };
+
+        writeln!(content, "{result}: {}", join(&values, " ")).unwrap();
+    }
+
+    content
+}
+
+/// `size` x `size` map with a few antenna frequencies, 2-4 antennas each.
+pub fn day08(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    const FREQUENCIES: &str = "0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+    let frequencies: Vec<char> = FREQUENCIES.chars().collect();
+
+    let mut rows = vec![vec!['.'; size]; size];
+
+    for _ in 0..(size / 4).max(1) {
+        let frequency = *rng.choose(&frequencies);
+
+        for _ in 0..rng.range(2..=4) {
+            rows[rng.index(size)][rng.index(size)] = frequency;
+        }
+    }
+
+    rows_to_string(&rows)
+}
+
+/// Disk map of `size` digits. Even size => the map ends with free space.
+pub fn day09(rng: &mut Rng, size: usize) -> String {
+    let mut content: String = (0..size)
+        .map(|i| {
+            let digit = match i % 2 {
+                0 => rng.range(1..=9), // file
+                _ => rng.range(0..=9), // free space
+            };
+            char::from_digit(digit as u32, 10).unwrap()
+        })
+        .collect();
+
+    content.push('\n');
+    content
+}
+
+/// `size` x `size` topographic map with random hiking trails (0 to 9 walks) over random heights.
+pub fn day10(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(1);
+
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| char::from_digit(rng.range(0..=9) as u32, 10).unwrap())
+                .collect()
+        })
+        .collect();
+
+    for _ in 0..(size * size / 20).max(1) {
+        let (mut x, mut y) = (rng.index(size), rng.index(size));
+
+        for height in 0..=9 {
+            rows[y][x] = char::from_digit(height, 10).unwrap();
+
+            let (dx, dy) = *rng.choose(&[(1, 0), (-1, 0), (0, 1), (0, -1)]);
+            x = (x as i64 + dx).clamp(0, size as i64 - 1) as usize;
+            y = (y as i64 + dy).clamp(0, size as i64 - 1) as usize;
+        }
+    }
+
+    rows_to_string(&rows)
+}
+
+/// `size` stones with values up to 6 digits, including zeros.
+pub fn day11(rng: &mut Rng, size: usize) -> String {
+    let stones: Vec<i64> = (0..size)
+        .map(|_| match rng.chance(1, 10) {
+            true => 0,
+            false => rng.range(1..=999_999),
+        })
+        .collect();
+
+    format!("{}\n", join(&stones, " "))
+}
+
+/// `size` x `size` garden with irregular regions of a few plant types.
+pub fn day12(rng: &mut Rng, size: usize) -> String {
+    const PLANTS: [char; 5] = ['A', 'B', 'C', 'D', 'E'];
+    let mut rows = vec![vec!['A'; size]; size];
+
+    for y in 0..size {
+        for x in 0..size {
+            // Copy a neighbour most of the time to form larger regions
+            rows[y][x] = match rng.range(0..=9) {
+                0..=3 if x > 0 => rows[y][x - 1],
+                4..=6 if y > 0 => rows[y - 1][x],
+                _ => *rng.choose(&PLANTS),
+            };
+        }
+    }
+
+    rows_to_string(&rows)
+}
+
+/// `size` claw machines, about half of them winnable. Buttons are never collinear.
+pub fn day13(rng: &mut Rng, size: usize) -> String {
+    let mut machines = vec![];
+
+    for _ in 0..size {
+        let (a, b) = loop {
+            let a = (rng.range(10..=99), rng.range(10..=99));
+            let b = (rng.range(10..=99), rng.range(10..=99));
+
+            if a.0 * b.1 != a.1 * b.0 {
+                break (a, b);
+            }
+        };
+
+        let prize = match rng.chance(1, 2) {
+            true => {
+                let (a_count, b_count) = (rng.range(1..=100), rng.range(1..=100));
+                (a_count * a.0 + b_count * b.0, a_count * a.1 + b_count * b.1)
+            }
+            false => (rng.range(1000..=20000), rng.range(1000..=20000)),
+        };
+
+        machines.push(format!(
+            "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+            a.0, a.1, b.0, b.1, prize.0, prize.1
+        ));
+    }
+
+    machines.join("\n")
+}
+
+/// `size` random robots plus a 12x12 block of robots which forms the Easter egg at a random iteration.
+pub fn day14(rng: &mut Rng, size: usize) -> String {
+    const PICTURE_SIDE: i64 = 12;
+    let (x_len, y_len) = (day14::X_LEN as i64, day14::Y_LEN as i64);
+
+    let random_velocity = |rng: &mut Rng| (rng.range(-100..=100), rng.range(-100..=100));
+    let mut content = String::new();
+
+    let picture_iteration = rng.range(1..=(x_len * y_len - 1));
+    let origin = (
+        rng.range(0..=(x_len - PICTURE_SIDE)),
+        rng.range(0..=(y_len - PICTURE_SIDE)),
+    );
+
+    for dy in 0..PICTURE_SIDE {
+        for dx in 0..PICTURE_SIDE {
+            let velocity = random_velocity(rng);
+
+            // Go back in time from the picture position
+            let x = (origin.0 + dx - velocity.0 * picture_iteration).rem_euclid(x_len);
+            let y = (origin.1 + dy - velocity.1 * picture_iteration).rem_euclid(y_len);
+
+            writeln!(content, "p={x},{y} v={},{}", velocity.0, velocity.1).unwrap();
+        }
+    }
+
+    for _ in 0..size {
+        let velocity = random_velocity(rng);
+        let (x, y) = (rng.range(0..=(x_len - 1)), rng.range(0..=(y_len - 1)));
+
+        writeln!(content, "p={x},{y} v={},{}", velocity.0, velocity.1).unwrap();
+    }
+
+    content
+}
+
+/// `size` x `size` walled warehouse with boxes and inner walls, followed by `size * 10` moves.
+pub fn day15(rng: &mut Rng, size: usize) -> String {
+    let size = size.max(3);
+
+    let mut rows: Vec<Vec<char>> = (0..size)
+        .map(|y| {
+            (0..size)
+                .map(|x| {
+                    if x == 0 || y == 0 || x == size - 1 || y == size - 1 {
+                        return '#';
+                    }
+                    match rng.range(0..=9) {
+                        0 => '#',
+                        1..=3 => 'O',
+                        _ => '.',
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rows[rng.range(1..=(size as i64 - 2)) as usize][rng.range(1..=(size as i64 - 2)) as usize] =
+        '@';
+
+    let mut content = rows_to_string(&rows);
+    content.push('\n');
+
+    let moves: Vec<char> = (0..size * 10)
+        .map(|_| *rng.choose(&['^', 'v', '<', '>']))
+        .collect();
+    for line in moves.chunks(70) {
+        writeln!(content, "{}", line.iter().collect::<String>()).unwrap();
+    }
+
+    content
+}
+
+fn grid(width: usize, height: usize, mut cell: impl FnMut() -> char) -> String {
+    let rows: Vec<Vec<char>> = (0..height)
+        .map(|_| (0..width).map(|_| cell()).collect())
+        .collect();
+
+    rows_to_string(&rows)
+}
+
+fn rows_to_string(rows: &[Vec<char>]) -> String {
+    let mut content = String::new();
+
+    for row in rows {
+        content.extend(row);
+        content.push('\n');
+    }
+
+    content
+}
+
+fn join(values: &[i64], separator: &str) -> String {
+    values
+        .iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<String>>()
+        .join(separator)
+}
@@ -0,0 +1,235 @@
+//! Directed graph with adjacency lists over generic node ids - ordering rules, dependencies and similar relations.
+//!
+//! Nodes keep their insertion order, which makes traversals and the topological sort deterministic.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+#[derive(Clone, Debug)]
+pub struct DiGraph<N> {
+    nodes: Vec<N>,
+    /// Node => its index in `nodes`
+    indexes: HashMap<N, usize>,
+    /// Successor indexes of each node
+    successors: Vec<Vec<usize>>,
+}
+
+impl<N> Default for DiGraph<N> {
+    fn default() -> Self {
+        Self {
+            nodes: vec![],
+            indexes: HashMap::new(),
+            successors: vec![],
+        }
+    }
+}
+
+impl<N: Copy + Eq + Hash> DiGraph<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a node without edges, no-op if it exists.
+    pub fn add_node(&mut self, node: N) {
+        self.index_or_insert(node);
+    }
+
+    /// Add an edge, missing nodes are added. Duplicate edges are ignored.
+    pub fn add_edge(&mut self, from: N, to: N) {
+        let from = self.index_or_insert(from);
+        let to = self.index_or_insert(to);
+
+        if !self.successors[from].contains(&to) {
+            self.successors[from].push(to);
+        }
+    }
+
+    fn index_or_insert(&mut self, node: N) -> usize {
+        if let Some(&index) = self.indexes.get(&node) {
+            return index;
+        }
+
+        self.nodes.push(node);
+        self.successors.push(vec![]);
+        self.indexes.insert(node, self.nodes.len() - 1);
+
+        self.nodes.len() - 1
+    }
+
+    pub fn contains(&self, node: N) -> bool {
+        self.indexes.contains_key(&node)
+    }
+
+    pub fn has_edge(&self, from: N, to: N) -> bool {
+        match (self.indexes.get(&from), self.indexes.get(&to)) {
+            (Some(from), Some(to)) => self.successors[*from].contains(to),
+            _ => false,
+        }
+    }
+
+    /// Nodes in insertion order.
+    pub fn nodes(&self) -> impl Iterator<Item = N> + '_ {
+        self.nodes.iter().copied()
+    }
+
+    /// Direct successors of `node`, none if it isn't in the graph.
+    pub fn successors(&self, node: N) -> impl Iterator<Item = N> + '_ {
+        let successors = match self.indexes.get(&node) {
+            Some(&index) => self.successors[index].as_slice(),
+            None => &[],
+        };
+
+        successors.iter().map(|&index| self.nodes[index])
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.successors.iter().map(Vec::len).sum()
+    }
+
+    /// Subgraph of the given nodes and the edges between them, nodes not in the graph are added without edges.
+    /// Node order follows `nodes`.
+    pub fn induced_subgraph(&self, nodes: impl IntoIterator<Item = N>) -> Self {
+        let mut subgraph = Self::new();
+        for node in nodes {
+            subgraph.add_node(node);
+        }
+
+        for from in 0..subgraph.nodes.len() {
+            let Some(&original) = self.indexes.get(&subgraph.nodes[from]) else {
+                continue;
+            };
+            for &to in &self.successors[original] {
+                if let Some(&to) = subgraph.indexes.get(&self.nodes[to]) {
+                    subgraph.successors[from].push(to);
+                }
+            }
+        }
+
+        subgraph
+    }
+
+    /// Kahn's algorithm - every node precedes its successors, ties are in insertion order.
+    /// `None` if the graph is cyclic, see `find_cycle`.
+    pub fn topological_sort(&self) -> Option<Vec<N>> {
+        let mut in_degrees = vec![0; self.nodes.len()];
+        for successors in &self.successors {
+            for &to in successors {
+                in_degrees[to] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<usize> = (0..self.nodes.len())
+            .filter(|&index| in_degrees[index] == 0)
+            .collect();
+        let mut sorted = Vec::with_capacity(self.nodes.len());
+        while let Some(index) = queue.pop_front() {
+            sorted.push(self.nodes[index]);
+            for &to in &self.successors[index] {
+                in_degrees[to] -= 1;
+                if in_degrees[to] == 0 {
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        // Nodes on (or behind) a cycle never reach zero in-degree
+        match sorted.len() == self.nodes.len() {
+            true => Some(sorted),
+            false => None,
+        }
+    }
+
+    /// Some cycle of the graph - `[a, b, c]` means `a -> b -> c -> a`. `None` if the graph is acyclic.
+    pub fn find_cycle(&self) -> Option<Vec<N>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            OnPath,
+            Done,
+        }
+        let mut states = vec![State::New; self.nodes.len()];
+
+        // Iterative DFS - the stack is the current path with the next successor to visit of each node
+        for root in 0..self.nodes.len() {
+            if states[root] != State::New {
+                continue;
+            }
+            let mut path: Vec<(usize, usize)> = vec![(root, 0)];
+            states[root] = State::OnPath;
+
+            while let Some((index, next_successor)) = path.last_mut() {
+                let index = *index;
+                let Some(&to) = self.successors[index].get(*next_successor) else {
+                    states[index] = State::Done;
+                    path.pop();
+                    continue;
+                };
+                *next_successor += 1;
+
+                match states[to] {
+                    State::New => {
+                        states[to] = State::OnPath;
+                        path.push((to, 0));
+                    }
+                    State::OnPath => {
+                        let cycle_start = path.iter().position(|&(index, _)| index == to).unwrap();
+                        return Some(
+                            path[cycle_start..]
+                                .iter()
+                                .map(|&(index, _)| self.nodes[index])
+                                .collect(),
+                        );
+                    }
+                    State::Done => (),
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Nodes reachable from `from` (including itself) in breadth-first order, empty if it isn't in the graph.
+    pub fn reachable(&self, from: N) -> Vec<N> {
+        let Some(&start) = self.indexes.get(&from) else {
+            return vec![];
+        };
+
+        let mut visited = vec![false; self.nodes.len()];
+        visited[start] = true;
+        let mut queue = VecDeque::from([start]);
+        let mut reachable = vec![];
+        while let Some(index) = queue.pop_front() {
+            reachable.push(self.nodes[index]);
+            for &to in &self.successors[index] {
+                if !visited[to] {
+                    visited[to] = true;
+                    queue.push_back(to);
+                }
+            }
+        }
+
+        reachable
+    }
+
+    /// Whether there is a path from `from` to `to` - every node reaches itself.
+    pub fn is_reachable(&self, from: N, to: N) -> bool {
+        self.contains(to) && self.reachable(from).contains(&to)
+    }
+}
+
+impl<N: Copy + Eq + Hash> FromIterator<(N, N)> for DiGraph<N> {
+    fn from_iter<I: IntoIterator<Item = (N, N)>>(edges: I) -> Self {
+        let mut graph = Self::new();
+        for (from, to) in edges {
+            graph.add_edge(from, to);
+        }
+
+        graph
+    }
+}
@@ -0,0 +1,284 @@
+//! N-dimensional counterparts of `Coordinates`, `Distance` and `Grid` - 3D cube puzzles, layered simulations, etc.
+//!
+//! Axis 0 is `x` and varies fastest in the dense storage (same as a row of the 2D `Grid`), axis 1 is `y` and so on.
+
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use crate::{
+    error::{Error, Result},
+    Coordinates, Grid,
+};
+
+/// Position within a `GridN` (non-negative).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Debug)]
+pub struct PointN<const N: usize>(pub [usize; N]);
+
+/// Difference of two points.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct VectorN<const N: usize>(pub [isize; N]);
+
+impl<const N: usize> PointN<N> {
+    /// Point moved by `vector`, `None` if a coordinate would be negative (or overflow).
+    pub fn checked_add(self, vector: VectorN<N>) -> Option<Self> {
+        let mut point = self;
+        for (coordinate, delta) in point.0.iter_mut().zip(vector.0) {
+            *coordinate = coordinate.checked_add_signed(delta)?;
+        }
+
+        Some(point)
+    }
+
+    /// Vector from `self` to `other`.
+    pub fn vector_to(&self, other: &Self) -> VectorN<N> {
+        VectorN(std::array::from_fn(|axis| {
+            other.0[axis] as isize - self.0[axis] as isize
+        }))
+    }
+
+    pub fn manhattan_distance(&self, other: &Self) -> usize {
+        self.vector_to(other).manhattan_length()
+    }
+}
+
+impl<const N: usize> VectorN<N> {
+    pub const ZERO: Self = Self([0; N]);
+
+    /// Unit vector along `axis` - positive or negative.
+    pub fn unit(axis: usize, positive: bool) -> Self {
+        let mut vector = Self::ZERO;
+        vector.0[axis] = if positive { 1 } else { -1 };
+
+        vector
+    }
+
+    /// The `2 * N` unit vectors along the axes.
+    pub fn orthogonal_units() -> impl Iterator<Item = Self> {
+        (0..N).flat_map(|axis| [Self::unit(axis, false), Self::unit(axis, true)])
+    }
+
+    /// The `3^N - 1` vectors with each coordinate in `-1..=1` (except zero) - orthogonal and diagonal neighbours.
+    pub fn all_units() -> impl Iterator<Item = Self> {
+        let count = 3usize.pow(N as u32);
+
+        (0..count)
+            .map(|mut index| {
+                Self(std::array::from_fn(|_| {
+                    let coordinate = (index % 3) as isize - 1;
+                    index /= 3;
+                    coordinate
+                }))
+            })
+            .filter(|vector| *vector != Self::ZERO)
+    }
+
+    pub fn manhattan_length(&self) -> usize {
+        self.0
+            .iter()
+            .map(|coordinate| coordinate.unsigned_abs())
+            .sum()
+    }
+}
+
+impl<const N: usize> Add for VectorN<N> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(std::array::from_fn(|axis| self.0[axis] + other.0[axis]))
+    }
+}
+
+impl<const N: usize> Sub for VectorN<N> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl<const N: usize> Neg for VectorN<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(self.0.map(|coordinate| -coordinate))
+    }
+}
+
+impl<const N: usize> Mul<isize> for VectorN<N> {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Self(self.0.map(|coordinate| coordinate * factor))
+    }
+}
+
+impl From<Coordinates> for PointN<2> {
+    fn from(coords: Coordinates) -> Self {
+        Self([coords.x, coords.y])
+    }
+}
+
+impl From<PointN<2>> for Coordinates {
+    fn from(PointN([x, y]): PointN<2>) -> Self {
+        Coordinates::new((x, y))
+    }
+}
+
+/// Dense N-dimensional grid - `dims[axis]` cells along each axis.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct GridN<T, const N: usize> {
+    dims: [usize; N],
+    cells: Vec<T>,
+}
+
+impl<T, const N: usize> GridN<T, N> {
+    pub fn new(dims: [usize; N], value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            dims,
+            cells: vec![value; dims.iter().product()],
+        }
+    }
+
+    /// Cell values computed from their points.
+    pub fn from_fn(dims: [usize; N], mut value: impl FnMut(PointN<N>) -> T) -> Self {
+        let cell_count = dims.iter().product();
+
+        Self {
+            dims,
+            cells: (0..cell_count)
+                .map(|index| value(point_at(dims, index)))
+                .collect(),
+        }
+    }
+
+    pub fn dims(&self) -> [usize; N] {
+        self.dims
+    }
+
+    /// Number of cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, point: PointN<N>) -> bool {
+        point
+            .0
+            .iter()
+            .zip(self.dims)
+            .all(|(&coordinate, dim)| coordinate < dim)
+    }
+
+    pub fn get(&self, point: PointN<N>) -> Option<&T> {
+        self.index_of(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, point: PointN<N>) -> Option<&mut T> {
+        self.index_of(point).map(|index| &mut self.cells[index])
+    }
+
+    fn index_of(&self, point: PointN<N>) -> Option<usize> {
+        if !self.contains(point) {
+            return None;
+        }
+
+        // Axis 0 fastest
+        Some(
+            point
+                .0
+                .iter()
+                .zip(self.dims)
+                .rev()
+                .fold(0, |index, (&coordinate, dim)| index * dim + coordinate),
+        )
+    }
+
+    /// All points, axis 0 varying fastest.
+    pub fn points(&self) -> impl Iterator<Item = PointN<N>> {
+        let dims = self.dims;
+
+        (0..self.cells.len()).map(move |index| point_at(dims, index))
+    }
+
+    /// Points with their values, in the order of `points`.
+    pub fn iter(&self) -> impl Iterator<Item = (PointN<N>, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    /// Orthogonal neighbours of `point` within the grid (up to `2 * N`).
+    pub fn neighbours(&self, point: PointN<N>) -> impl Iterator<Item = PointN<N>> + '_ {
+        self.neighbours_by(point, VectorN::orthogonal_units())
+    }
+
+    /// Orthogonal and diagonal neighbours of `point` within the grid (up to `3^N - 1`).
+    pub fn all_neighbours(&self, point: PointN<N>) -> impl Iterator<Item = PointN<N>> + '_ {
+        self.neighbours_by(point, VectorN::all_units())
+    }
+
+    fn neighbours_by<'a>(
+        &'a self,
+        point: PointN<N>,
+        vectors: impl Iterator<Item = VectorN<N>> + 'a,
+    ) -> impl Iterator<Item = PointN<N>> + 'a {
+        vectors
+            .filter_map(move |vector| point.checked_add(vector))
+            .filter(|neighbour| self.contains(*neighbour))
+    }
+}
+
+impl<T> GridN<T, 2> {
+    /// Grid of equally long rows (`rows[y][x]`).
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|row| row.len() != width) {
+            return Err(Error::at_line(
+                y + 1,
+                format!("Row must have the same length ({width}) as the first one."),
+            ));
+        }
+
+        Ok(Self {
+            dims: [width, rows.len()],
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+}
+
+impl<T: Clone> TryFrom<&Grid<T>> for GridN<T, 2> {
+    type Error = Error;
+
+    fn try_from(grid: &Grid<T>) -> Result<Self> {
+        Self::from_rows(grid.get_rows().clone())
+    }
+}
+
+impl<T, const N: usize> Index<PointN<N>> for GridN<T, N> {
+    type Output = T;
+
+    fn index(&self, point: PointN<N>) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("Point {point:?} out of grid {:?}.", self.dims))
+    }
+}
+
+impl<T, const N: usize> IndexMut<PointN<N>> for GridN<T, N> {
+    fn index_mut(&mut self, point: PointN<N>) -> &mut T {
+        let dims = self.dims;
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("Point {point:?} out of grid {dims:?}."))
+    }
+}
+
+/// Point of the cell stored at `index`.
+fn point_at<const N: usize>(dims: [usize; N], mut index: usize) -> PointN<N> {
+    PointN(std::array::from_fn(|axis| {
+        let coordinate = index % dims[axis];
+        index /= dims[axis];
+        coordinate
+    }))
+}
@@ -0,0 +1,319 @@
+//! Export of grids as images - PNG (single frame or sequence) and animated GIF.
+//! Encoders are minimal and dependency free: PNG uses uncompressed deflate blocks, GIF uses LZW with a global palette.
+
+use std::{collections::HashMap, fs};
+
+use crate::{
+    error::{Error, Result},
+    Grid,
+};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+pub const BLACK: Rgb = Rgb(0, 0, 0);
+
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    /// Row by row
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    /// Each grid point is a `scale` x `scale` square coloured by `colour`. Missing points of jagged rows are black.
+    pub fn from_grid<T>(grid: &Grid<T>, scale: usize, colour: impl Fn(&T) -> Rgb) -> Self {
+        let rows = grid.get_rows();
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0) * scale;
+        let height = rows.len() * scale;
+        let mut pixels = Vec::with_capacity(width * height);
+
+        for row in rows {
+            let mut pixel_row: Vec<Rgb> = row
+                .iter()
+                .flat_map(|point| [colour(point)].repeat(scale))
+                .collect();
+            pixel_row.resize(width, BLACK);
+
+            for _ in 0..scale {
+                pixels.extend(&pixel_row);
+            }
+        }
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+}
+
+/// PNG file (8-bit RGB).
+pub fn encode_png(image: &Image) -> Vec<u8> {
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+    let mut header = vec![];
+    header.extend((image.width as u32).to_be_bytes());
+    header.extend((image.height as u32).to_be_bytes());
+    header.extend([8, 2, 0, 0, 0]); // bit depth, colour type RGB, compression, filter, interlace
+    write_png_chunk(&mut png, b"IHDR", &header);
+
+    // Scanlines - filter type 0 (none) + pixels
+    let mut raw = Vec::with_capacity(image.height * (image.width * 3 + 1));
+    for row in image.pixels.chunks(image.width.max(1)).take(image.height) {
+        raw.push(0);
+        for pixel in row {
+            raw.extend([pixel.0, pixel.1, pixel.2]);
+        }
+    }
+    write_png_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+    write_png_chunk(&mut png, b"IEND", &[]);
+
+    png
+}
+
+/// Animated GIF looping forever, `delay` between frames in hundredths of a second.
+/// All frames must have the same size and use at most 256 colours in total.
+pub fn encode_gif(frames: &[Image], delay: u16) -> Result<Vec<u8>> {
+    let first = frames
+        .first()
+        .ok_or_else(|| Error::new("No frames to encode."))?;
+    let (width, height) = (first.width, first.height);
+
+    if frames
+        .iter()
+        .any(|frame| frame.width != width || frame.height != height)
+    {
+        return Err(Error::new("All frames must have the same size."));
+    }
+    let (Ok(gif_width), Ok(gif_height)) = (u16::try_from(width), u16::try_from(height)) else {
+        return Err(Error::new(format!(
+            "Image {width}x{height} is too large for GIF."
+        )));
+    };
+
+    // Global palette
+    let mut palette: Vec<Rgb> = vec![];
+    let mut palette_indexes: HashMap<Rgb, u8> = HashMap::new();
+    for pixel in frames.iter().flat_map(|frame| &frame.pixels) {
+        if palette_indexes.contains_key(pixel) {
+            continue;
+        }
+        if palette.len() == 256 {
+            return Err(Error::new("GIF supports at most 256 colours."));
+        }
+        palette_indexes.insert(*pixel, palette.len() as u8);
+        palette.push(*pixel);
+    }
+
+    // Palette size is 2^(table_bits + 1)
+    let table_bits = (palette.len().max(2).next_power_of_two().trailing_zeros() - 1) as u8;
+    palette.resize(2 << table_bits, BLACK);
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend(gif_width.to_le_bytes());
+    gif.extend(gif_height.to_le_bytes());
+    gif.extend([0x80 | 0x70 | table_bits, 0, 0]); // global palette, colour resolution, background, aspect ratio
+    for colour in &palette {
+        gif.extend([colour.0, colour.1, colour.2]);
+    }
+
+    // Loop forever
+    gif.extend([0x21, 0xFF, 0x0B]);
+    gif.extend(b"NETSCAPE2.0");
+    gif.extend([0x03, 0x01, 0x00, 0x00, 0x00]);
+
+    let min_code_size = (table_bits + 1).max(2);
+    for frame in frames {
+        // Graphic control extension (delay) + image descriptor
+        gif.extend([0x21, 0xF9, 0x04, 0x00]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0x00, 0x00, 0x2C, 0, 0, 0, 0]);
+        gif.extend(gif_width.to_le_bytes());
+        gif.extend(gif_height.to_le_bytes());
+        gif.push(0x00);
+
+        let indexes: Vec<u8> = frame
+            .pixels
+            .iter()
+            .map(|pixel| palette_indexes[pixel])
+            .collect();
+
+        gif.push(min_code_size);
+        for block in lzw_encode(&indexes, min_code_size).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0x00);
+    }
+
+    gif.push(0x3B);
+
+    Ok(gif)
+}
+
+/// Write frames as an animated GIF, see `encode_gif()`.
+pub fn save_gif(frames: &[Image], delay: u16, path: &str) -> Result<()> {
+    fs::write(path, encode_gif(frames, delay)?)
+        .map_err(|err| Error::new(format!("Failed to write file: {err}")).with_file(path))
+}
+
+/// Write frames as `<dir>/<prefix>_0000.png`, `<dir>/<prefix>_0001.png`, ... Returns the file paths.
+pub fn save_png_sequence(frames: &[Image], dir: &str, prefix: &str) -> Result<Vec<String>> {
+    fs::create_dir_all(dir)
+        .map_err(|err| Error::new(format!("Failed to create directory: {err}")).with_file(dir))?;
+
+    let mut paths = vec![];
+    for (i, frame) in frames.iter().enumerate() {
+        let path = format!("{dir}/{prefix}_{i:04}.png");
+        fs::write(&path, encode_png(frame))
+            .map_err(|err| Error::new(format!("Failed to write file: {err}")).with_file(&path))?;
+        paths.push(path);
+    }
+
+    Ok(paths)
+}
+
+fn write_png_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+
+    let start = png.len();
+    png.extend(chunk_type);
+    png.extend(data);
+    let crc = crc32(&png[start..]);
+
+    png.extend(crc.to_be_bytes());
+}
+
+/// Zlib stream with uncompressed (stored) deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut zlib = vec![0x78, 0x01];
+
+    let blocks: Vec<&[u8]> = match data.is_empty() {
+        true => vec![&[]],
+        false => data.chunks(u16::MAX as usize).collect(),
+    };
+    for (i, block) in blocks.iter().enumerate() {
+        let is_final = i == blocks.len() - 1;
+        let length = block.len() as u16;
+
+        zlib.push(is_final as u8);
+        zlib.extend(length.to_le_bytes());
+        zlib.extend((!length).to_le_bytes());
+        zlib.extend(*block);
+    }
+
+    zlib.extend(adler32(data).to_be_bytes());
+
+    zlib
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = u32::MAX;
+
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xEDB88320,
+                _ => crc >> 1,
+            };
+        }
+    }
+
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+
+    for byte in data {
+        a = (a + u32::from(*byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+
+    (b << 16) | a
+}
+
+/// GIF flavour of LZW - variable code size up to 12 bits, codes packed LSB first.
+fn lzw_encode(indexes: &[u8], min_code_size: u8) -> Vec<u8> {
+    const MAX_CODE: u16 = 4096;
+
+    let clear_code: u16 = 1 << min_code_size;
+    let end_code = clear_code + 1;
+
+    let mut output = BitWriter::default();
+    let mut dictionary: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut code_size = min_code_size + 1;
+    let mut next_code = end_code + 1;
+
+    output.write(clear_code, code_size);
+
+    let Some((&first, rest)) = indexes.split_first() else {
+        output.write(end_code, code_size);
+        return output.finish();
+    };
+
+    let mut prefix = u16::from(first);
+    for &index in rest {
+        if let Some(&code) = dictionary.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+
+        output.write(prefix, code_size);
+
+        // Decoder lags one code behind - grow the code size once it has `1 << code_size` codes
+        if next_code == 1 << code_size && code_size < 12 {
+            code_size += 1;
+        }
+
+        if next_code < MAX_CODE {
+            dictionary.insert((prefix, index), next_code);
+            next_code += 1;
+        } else {
+            output.write(clear_code, code_size);
+            dictionary.clear();
+            code_size = min_code_size + 1;
+            next_code = end_code + 1;
+        }
+
+        prefix = u16::from(index);
+    }
+
+    output.write(prefix, code_size);
+    if next_code == 1 << code_size && code_size < 12 {
+        code_size += 1;
+    }
+    output.write(end_code, code_size);
+
+    output.finish()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bit_count: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, size: u8) {
+        self.buffer |= u32::from(code) << self.bit_count;
+        self.bit_count += size;
+
+        while self.bit_count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bit_count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bit_count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+
+        self.bytes
+    }
+}
@@ -0,0 +1,104 @@
+//! Loading of puzzle inputs.
+//! All inputs are normalised so that solvers don't have to care about the origin of the file.
+
+use std::fs;
+
+use crate::error::{Error, Result};
+
+const BYTE_ORDER_MARK: char = '\u{FEFF}';
+
+pub const RESOURCES_DIR: &str = "./resources";
+
+/// Path to the puzzle input of the day, e.g. `./resources/2024_09.txt`.
+pub fn input_path(year: u16, day: u8) -> String {
+    format!("{RESOURCES_DIR}/{year}_{day:02}.txt")
+}
+
+/// Directory with multiple inputs of the day (e.g. one per teammate), e.g. `./resources/2024/05`.
+pub fn input_dir(year: u16, day: u8) -> String {
+    format!("{RESOURCES_DIR}/{year}/{day:02}")
+}
+
+/// Input files at path - the path itself if it is a file, all `*.txt` files (sorted) if it is a directory.
+pub fn list_inputs(path: &str) -> Result<Vec<String>> {
+    let metadata = fs::metadata(path)
+        .map_err(|err| Error::new(format!("Failed to read path: {err}")).with_file(path))?;
+
+    if !metadata.is_dir() {
+        return Ok(vec![path.to_string()]);
+    }
+
+    let entries = fs::read_dir(path)
+        .map_err(|err| Error::new(format!("Failed to read directory: {err}")).with_file(path))?;
+
+    let mut paths: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|entry_path| entry_path.is_file())
+        .filter(|entry_path| {
+            entry_path
+                .extension()
+                .is_some_and(|extension| extension == "txt")
+        })
+        .map(|entry_path| entry_path.to_string_lossy().to_string())
+        .collect();
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Paths to example inputs of the day, e.g. `./resources/2024_09_example.txt`, `./resources/2024_09_example_2.txt`.
+pub fn example_paths(year: u16, day: u8) -> Vec<String> {
+    example_paths_in(RESOURCES_DIR, year, day)
+}
+
+/// Same as `example_paths()` for another directory.
+pub fn example_paths_in(dir: &str, year: u16, day: u8) -> Vec<String> {
+    let prefix = format!("{year}_{day:02}_example");
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let mut paths: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|file_name| file_name.starts_with(&prefix) && file_name.ends_with(".txt"))
+        .map(|file_name| format!("{dir}/{file_name}"))
+        .collect();
+    paths.sort();
+
+    paths
+}
+
+/// Read and normalise puzzle input file. See `normalize()`.
+pub fn read_input(file_path: &str) -> Result<String> {
+    match fs::read_to_string(file_path) {
+        Ok(text) => {
+            let input = normalize(&text);
+            tracing::debug!(
+                path = file_path,
+                bytes = input.len(),
+                lines = input.lines().count(),
+                "read input"
+            );
+            Ok(input)
+        }
+        Err(err) => Err(Error::new(format!("Failed to read file: {err}")).with_file(file_path)),
+    }
+}
+
+/// Normalise puzzle input:
+/// - strip leading byte order mark,
+/// - convert CRLF (and lone CR) line endings to LF,
+/// - strip trailing whitespace (including trailing newlines) at the end of the input.
+pub fn normalize(content: &str) -> String {
+    let content = content.strip_prefix(BYTE_ORDER_MARK).unwrap_or(content);
+
+    content
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .trim_end()
+        .to_string()
+}
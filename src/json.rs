@@ -0,0 +1,304 @@
+//! Minimal JSON parser - enough for exports like the private leaderboard (see `crate::leaderboard`).
+//! Numbers are `f64`, integers are exact up to 2^53 (timestamps, ids and scores fit).
+//! Arrays and objects nest at most `MAX_DEPTH` levels, the parser is recursive.
+
+use std::{collections::BTreeMap, iter::Peekable, str::Chars};
+
+use crate::error::{Error, Result};
+
+pub const MAX_DEPTH: usize = 128;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    Array(Vec<Value>),
+    Object(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Parse a JSON document, trailing content other than whitespace is an error.
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut parser = Parser {
+            chars: text.chars().peekable(),
+            line: 1,
+            column: 1,
+            depth: 0,
+        };
+
+        let value = parser.value()?;
+        parser.skip_whitespace();
+        match parser.chars.peek() {
+            None => Ok(value),
+            Some(_) => Err(parser.error("Unexpected content after the value.")),
+        }
+    }
+
+    /// Member of an object, `None` for other values.
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.as_object()?.get(key)
+    }
+
+    pub fn as_object(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Value::Object(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Non-negative integral number.
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Value::Number(value)
+                if *value >= 0.0 && value.fract() == 0.0 && *value < 2f64.powi(64) =>
+            {
+                Some(*value as u64)
+            }
+            _ => None,
+        }
+    }
+}
+
+struct Parser<'a> {
+    chars: Peekable<Chars<'a>>,
+    /// Position of the next character
+    line: usize,
+    column: usize,
+    /// Arrays and objects currently open
+    depth: usize,
+}
+
+impl Parser<'_> {
+    fn error(&self, description: impl Into<String>) -> Error {
+        Error::at(self.line, self.column, description)
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let character = self.chars.next()?;
+        match character {
+            '\n' => {
+                self.line += 1;
+                self.column = 1;
+            }
+            _ => self.column += 1,
+        }
+
+        Some(character)
+    }
+
+    fn skip_whitespace(&mut self) {
+        while self
+            .chars
+            .peek()
+            .is_some_and(|character| matches!(character, ' ' | '\t' | '\n' | '\r'))
+        {
+            self.next();
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<()> {
+        self.skip_whitespace();
+        match self.chars.peek() {
+            Some(&character) if character == expected => {
+                self.next();
+                Ok(())
+            }
+            _ => Err(self.error(format!("Expected '{expected}'."))),
+        }
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        self.skip_whitespace();
+
+        match self.chars.peek() {
+            Some('{') => self.nested(Self::object),
+            Some('[') => self.nested(Self::array),
+            Some('"') => Ok(Value::String(self.string()?)),
+            Some('-' | '0'..='9') => self.number(),
+            Some('t') => self.literal("true", Value::Bool(true)),
+            Some('f') => self.literal("false", Value::Bool(false)),
+            Some('n') => self.literal("null", Value::Null),
+            Some(_) => Err(self.error("Expected a value.")),
+            None => Err(self.error("Unexpected end, expected a value.")),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value>) -> Result<Value> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(format!("Nested deeper than {MAX_DEPTH} levels.")));
+        }
+
+        self.depth += 1;
+        let value = parse(self);
+        self.depth -= 1;
+
+        value
+    }
+
+    fn literal(&mut self, literal: &str, value: Value) -> Result<Value> {
+        let error = self.error(format!("Expected '{literal}'."));
+        for expected in literal.chars() {
+            if self.next() != Some(expected) {
+                return Err(error);
+            }
+        }
+
+        Ok(value)
+    }
+
+    fn object(&mut self) -> Result<Value> {
+        let mut members = BTreeMap::new();
+        self.expect('{')?;
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&'}') {
+            self.next();
+            return Ok(Value::Object(members));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.chars.peek() != Some(&'"') {
+                return Err(self.error("Expected a member name in double quotes."));
+            }
+            let name = self.string()?;
+            self.expect(':')?;
+            members.insert(name, self.value()?);
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some('}') => return Ok(Value::Object(members)),
+                _ => return Err(self.error("Expected ',' or '}'.")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value> {
+        let mut values = vec![];
+        self.expect('[')?;
+
+        self.skip_whitespace();
+        if self.chars.peek() == Some(&']') {
+            self.next();
+            return Ok(Value::Array(values));
+        }
+
+        loop {
+            values.push(self.value()?);
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => continue,
+                Some(']') => return Ok(Value::Array(values)),
+                _ => return Err(self.error("Expected ',' or ']'.")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let mut value = String::new();
+        self.next(); // opening quote
+
+        loop {
+            match self.next() {
+                Some('"') => return Ok(value),
+                Some('\\') => {
+                    let escaped = match self.next() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => self.unicode_escape()?,
+                        _ => return Err(self.error("Invalid escape sequence.")),
+                    };
+                    value.push(escaped);
+                }
+                Some(character) if character < ' ' => {
+                    return Err(self.error("Control character in a string."))
+                }
+                Some(character) => value.push(character),
+                None => return Err(self.error("Unterminated string.")),
+            }
+        }
+    }
+
+    /// `\uXXXX` after the `u`, including UTF-16 surrogate pairs. Lone surrogates are replaced.
+    fn unicode_escape(&mut self) -> Result<char> {
+        let high = self.hex_code()?;
+        if !(0xD800..0xDC00).contains(&high) {
+            return Ok(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER));
+        }
+
+        if self.next() != Some('\\') || self.next() != Some('u') {
+            return Ok(char::REPLACEMENT_CHARACTER);
+        }
+        let low = self.hex_code()?;
+        let code = 0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00) & 0x3FF);
+
+        Ok(char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn hex_code(&mut self) -> Result<u32> {
+        let mut code = 0;
+        for _ in 0..4 {
+            let digit = self
+                .next()
+                .and_then(|character| character.to_digit(16))
+                .ok_or_else(|| self.error("Expected four hexadecimal digits."))?;
+            code = code * 16 + digit;
+        }
+
+        Ok(code)
+    }
+
+    fn number(&mut self) -> Result<Value> {
+        let error = self.error("Invalid number.");
+        let mut raw = String::new();
+
+        while let Some(&character) = self.chars.peek() {
+            if !matches!(character, '-' | '+' | '.' | 'e' | 'E' | '0'..='9') {
+                break;
+            }
+            raw.push(character);
+            self.next();
+        }
+
+        // Rust accepts a superset (e.g. leading `+`, `.5`) - check the JSON grammar first
+        let unsigned = raw.strip_prefix('-').unwrap_or(&raw);
+        let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (unsigned, None),
+        };
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (mantissa, None),
+        };
+        let is_digits = |part: &str| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit());
+
+        let is_valid = is_digits(integer)
+            && (integer == "0" || !integer.starts_with('0'))
+            && fraction.is_none_or(is_digits)
+            && exponent.is_none_or(|exponent| {
+                is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent))
+            });
+
+        match is_valid {
+            true => raw.parse().map(Value::Number).map_err(|_| error),
+            false => Err(error),
+        }
+    }
+}
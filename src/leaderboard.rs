@@ -0,0 +1,402 @@
+//! Private leaderboard report - ranking, per-day completion times and star deltas from the leaderboard JSON export
+//! (`https://adventofcode.com/<year>/leaderboard/private/view/<id>.json`).
+//!
+//! Source is a downloaded file or an `http://` URL, e.g. a local stand-in endpoint serving the export. HTTPS is not
+//! supported, so the session cookie (see `crate::config`) is only sent on request and only to a loopback host.
+//! Fetched exports are kept in the cache directory and reused for 15 minutes (the site asks not to request them
+//! more often).
+//!
+//! Completion time is counted from the puzzle unlock (midnight UTC-5), star delta is Part Two - Part One.
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs,
+    io::{Read, Write as _},
+    net::{IpAddr, TcpStream},
+    time::Duration,
+};
+
+use crate::{
+    error::{Error, Result},
+    json::Value,
+    stats::format_duration,
+};
+
+const HTTP_TIMEOUT: Duration = Duration::from_secs(10);
+const CACHE_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+/// Puzzles unlock at midnight UTC-5
+const UNLOCK_HOUR_UTC: u64 = 5;
+const FIRST_EVENT: u16 = 2015;
+
+pub struct Leaderboard {
+    pub year: u16,
+    pub owner_id: u64,
+    pub members: Vec<Member>,
+}
+
+pub struct Member {
+    pub id: u64,
+    /// AoC shows anonymous users by their id.
+    pub name: String,
+    pub local_score: u64,
+    pub stars: u64,
+    /// Timestamp of the last star, `None` without stars.
+    pub last_star: Option<u64>,
+    /// Star timestamps by day - Part One and Part Two.
+    pub days: BTreeMap<u8, [Option<u64>; 2]>,
+}
+
+impl Leaderboard {
+    /// Load from a file or an `http://` URL, see the module documentation.
+    /// `session_token` is sent as the cookie - fails for a host other than `localhost` or a loopback address.
+    pub fn load(source: &str, session_token: Option<&str>) -> Result<Self> {
+        let json = match source.starts_with("http://") {
+            true => fetch(source, session_token)?,
+            false => fs::read_to_string(source).map_err(|err| {
+                Error::new(format!("Failed to read file: {err}")).with_file(source)
+            })?,
+        };
+
+        Self::parse(&json).map_err(|err| err.with_file(source))
+    }
+
+    /// Same as `load()`, but an export fetched from an `http://` URL is saved to `cache_dir` and reused while it is
+    /// younger than 15 minutes.
+    pub fn load_cached(source: &str, session_token: Option<&str>, cache_dir: &str) -> Result<Self> {
+        if !source.starts_with("http://") {
+            return Self::load(source, session_token);
+        }
+
+        let cache_path = format!("{cache_dir}/{}", cache_file_name(source));
+        let is_fresh = fs::metadata(&cache_path)
+            .and_then(|metadata| metadata.modified())
+            .ok()
+            .and_then(|modified| modified.elapsed().ok())
+            .is_some_and(|age| age < CACHE_MAX_AGE);
+        if is_fresh {
+            tracing::debug!(path = cache_path, "cached leaderboard");
+            return Self::load(&cache_path, None);
+        }
+
+        let json = fetch(source, session_token)?;
+        let leaderboard = Self::parse(&json).map_err(|err| err.with_file(source))?;
+
+        // Only valid exports are cached
+        fs::create_dir_all(cache_dir)
+            .and_then(|_| fs::write(&cache_path, &json))
+            .map_err(|err| {
+                Error::new(format!("Failed to write file: {err}")).with_file(&cache_path)
+            })?;
+
+        Ok(leaderboard)
+    }
+
+    pub fn parse(json: &str) -> Result<Self> {
+        let root = Value::parse(json)?;
+        let invalid = |description: &str| Error::new(format!("Invalid leaderboard: {description}"));
+
+        let year = root
+            .get("event")
+            .and_then(Value::as_str)
+            .and_then(|event| event.parse().ok())
+            .ok_or_else(|| invalid("`event` year expected."))?;
+        if year < FIRST_EVENT {
+            return Err(invalid(&format!(
+                "`event` year {year} is before the first event ({FIRST_EVENT})."
+            )));
+        }
+        let owner_id = root
+            .get("owner_id")
+            .and_then(Value::as_u64)
+            .ok_or_else(|| invalid("`owner_id` expected."))?;
+        let raw_members = root
+            .get("members")
+            .and_then(Value::as_object)
+            .ok_or_else(|| invalid("`members` object expected."))?;
+
+        let mut members = vec![];
+        for (key, raw_member) in raw_members {
+            let invalid_member =
+                |field: &str| invalid(&format!("member {key} - `{field}` expected."));
+            let number = |field: &str| {
+                raw_member
+                    .get(field)
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| invalid_member(field))
+            };
+
+            let id = number("id")?;
+            let name = match raw_member.get("name") {
+                Some(Value::String(name)) => name.clone(),
+                Some(Value::Null) | None => format!("(anonymous user #{id})"),
+                Some(_) => return Err(invalid_member("name")),
+            };
+            let last_star = match number("last_star_ts")? {
+                0 => None,
+                timestamp => Some(timestamp),
+            };
+
+            // {"<day>": {"<part>": {"get_star_ts": <timestamp>, ...}}}
+            let mut days = BTreeMap::new();
+            let raw_days = raw_member
+                .get("completion_day_level")
+                .and_then(Value::as_object)
+                .ok_or_else(|| invalid_member("completion_day_level"))?;
+            for (raw_day, raw_parts) in raw_days {
+                let day = raw_day
+                    .parse()
+                    .ok()
+                    .filter(|day| (1..=25).contains(day))
+                    .ok_or_else(|| invalid_member("completion_day_level"))?;
+                let star = |part: &str| match raw_parts.get(part) {
+                    None => Ok(None),
+                    Some(raw_part) => raw_part
+                        .get("get_star_ts")
+                        .and_then(Value::as_u64)
+                        .map(Some)
+                        .ok_or_else(|| invalid_member("get_star_ts")),
+                };
+                days.insert(day, [star("1")?, star("2")?]);
+            }
+
+            members.push(Member {
+                id,
+                name,
+                local_score: number("local_score")?,
+                stars: number("stars")?,
+                last_star,
+                days,
+            });
+        }
+
+        Ok(Self {
+            year,
+            owner_id,
+            members,
+        })
+    }
+
+    /// Members by local score, ties broken by stars and then by who got the last star earlier (as on the site).
+    pub fn ranking(&self) -> Vec<&Member> {
+        let mut ranking: Vec<&Member> = self.members.iter().collect();
+        ranking.sort_by_key(|member| {
+            (
+                std::cmp::Reverse(member.local_score),
+                std::cmp::Reverse(member.stars),
+                member.last_star.unwrap_or(u64::MAX),
+                member.id,
+            )
+        });
+
+        ranking
+    }
+
+    /// Ranking followed by a table of each day with stars (only `day` if set).
+    pub fn format_report(&self, day: Option<u8>) -> String {
+        let mut report = String::new();
+        let ranking = self.ranking();
+        let name_width = self
+            .members
+            .iter()
+            .map(|member| member.name.chars().count())
+            .max()
+            .unwrap_or(0)
+            .max(4);
+
+        writeln!(
+            report,
+            "Private leaderboard {} (owner {}), {} members\n",
+            self.year,
+            self.owner_id,
+            self.members.len()
+        )
+        .unwrap();
+        writeln!(report, "Rank  Score  Stars  Name").unwrap();
+        for (i, member) in ranking.iter().enumerate() {
+            writeln!(
+                report,
+                "{:>4}  {:>5}  {:>5}  {}",
+                i + 1,
+                member.local_score,
+                member.stars,
+                member.name
+            )
+            .unwrap();
+        }
+
+        let days: Vec<u8> = match day {
+            Some(day) => vec![day],
+            None => {
+                let mut days: Vec<u8> = self
+                    .members
+                    .iter()
+                    .flat_map(|member| member.days.keys().copied())
+                    .collect();
+                days.sort_unstable();
+                days.dedup();
+                days
+            }
+        };
+
+        for day in days {
+            let unlock = unlock_time(self.year, day);
+            let since_unlock = |timestamp: Option<u64>| {
+                timestamp.map(|timestamp| timestamp.saturating_sub(unlock))
+            };
+
+            // Fastest Part Two first, then fastest Part One, members without stars are left out
+            let mut rows: Vec<(&str, Option<u64>, Option<u64>)> = self
+                .members
+                .iter()
+                .filter_map(|member| {
+                    let [part_one, part_two] = member.days.get(&day)?;
+                    Some((
+                        member.name.as_str(),
+                        since_unlock(*part_one),
+                        since_unlock(*part_two),
+                    ))
+                })
+                .collect();
+            rows.sort_by_key(|&(name, part_one, part_two)| {
+                (
+                    part_two.unwrap_or(u64::MAX),
+                    part_one.unwrap_or(u64::MAX),
+                    name,
+                )
+            });
+
+            writeln!(
+                report,
+                "\nDay {day} - time since unlock, delta is Part Two - Part One"
+            )
+            .unwrap();
+            writeln!(
+                report,
+                "{:<name_width$}  {:>9}  {:>9}  {:>9}",
+                "Name", "Part One", "Part Two", "Delta"
+            )
+            .unwrap();
+            if rows.is_empty() {
+                writeln!(report, "No stars.").unwrap();
+            }
+
+            let time = |seconds: Option<u64>| seconds.map_or("-".to_string(), format_duration);
+            for (name, part_one, part_two) in rows {
+                let delta = part_one
+                    .zip(part_two)
+                    .map(|(part_one, part_two)| part_two.saturating_sub(part_one));
+                writeln!(
+                    report,
+                    "{:<name_width$}  {:>9}  {:>9}  {:>9}",
+                    name,
+                    time(part_one),
+                    time(part_two),
+                    time(delta)
+                )
+                .unwrap();
+            }
+        }
+
+        report
+    }
+}
+
+/// Timestamp of the puzzle unlock - December `day`, midnight UTC-5. `year` must not be before 1970.
+pub fn unlock_time(year: u16, day: u8) -> u64 {
+    let days = days_from_civil(i64::from(year), 12, i64::from(day));
+
+    days as u64 * 24 * 3600 + UNLOCK_HOUR_UTC * 3600
+}
+
+/// Days since the Unix epoch of a Gregorian date - http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * month_index + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Cache file of the export at `url`, e.g. `leaderboard_localhost_8080_2024_1.json`.
+fn cache_file_name(url: &str) -> String {
+    let address: String = url
+        .strip_prefix("http://")
+        .unwrap_or(url)
+        .trim_end_matches(".json")
+        .chars()
+        .map(|character| match character.is_ascii_alphanumeric() {
+            true => character,
+            false => '_',
+        })
+        .collect();
+
+    format!("leaderboard_{address}.json")
+}
+
+/// Body of a plain HTTP GET (HTTP/1.0 - no chunked transfer encoding).
+fn fetch(url: &str, session_token: Option<&str>) -> Result<String> {
+    let request_error = |description: String| Error::new(description).with_file(url);
+
+    let address = url.strip_prefix("http://").unwrap_or(url);
+    let (host, path) = match address.split_once('/') {
+        Some((host, path)) => (host, format!("/{path}")),
+        None => (address, "/".to_string()),
+    };
+    let host_port = match host.contains(':') {
+        true => host.to_string(),
+        false => format!("{host}:80"),
+    };
+
+    // Plain HTTP - the cookie would travel unencrypted
+    if session_token.is_some() && !is_loopback(host) {
+        return Err(request_error(format!(
+            "Refusing to send the session cookie over plain HTTP to '{host}', only loopback hosts are allowed."
+        )));
+    }
+
+    let mut stream = TcpStream::connect(&host_port)
+        .map_err(|err| request_error(format!("Failed to connect: {err}")))?;
+    stream
+        .set_read_timeout(Some(HTTP_TIMEOUT))
+        .map_err(|err| request_error(format!("Failed to connect: {err}")))?;
+
+    let mut request = format!("GET {path} HTTP/1.0\r\nHost: {host}\r\n");
+    if let Some(token) = session_token {
+        request.push_str(&format!("Cookie: session={token}\r\n"));
+    }
+    request.push_str("\r\n");
+
+    let mut response = vec![];
+    stream
+        .write_all(request.as_bytes())
+        .and_then(|_| stream.read_to_end(&mut response))
+        .map_err(|err| request_error(format!("Request failed: {err}")))?;
+
+    let response = String::from_utf8_lossy(&response);
+    let (head, body) = response
+        .split_once("\r\n\r\n")
+        .ok_or_else(|| request_error("Invalid HTTP response.".to_string()))?;
+    let status = head.lines().next().unwrap_or_default();
+    match status.split_whitespace().nth(1) {
+        Some("200") => Ok(body.to_string()),
+        _ => Err(request_error(format!("Request failed: {status}"))),
+    }
+}
+
+/// Whether `host` (with an optional port) is `localhost` or a loopback IP address.
+fn is_loopback(host: &str) -> bool {
+    let name = match host.rsplit_once(':') {
+        Some((name, port)) if port.chars().all(|character| character.is_ascii_digit()) => name,
+        _ => host,
+    };
+    let name = name.trim_start_matches('[').trim_end_matches(']');
+
+    name.eq_ignore_ascii_case("localhost")
+        || name
+            .parse::<IpAddr>()
+            .is_ok_and(|address| address.is_loopback())
+}
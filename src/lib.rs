@@ -1,27 +1,59 @@
-use std::{fs, str::FromStr};
-
-pub fn get_columns_from_file<T: FromStr, const N: usize>(
-    file_path: &str,
-) -> Result<[Vec<T>; N], String> {
+use std::str::FromStr;
+
+pub mod animation;
+pub mod answers;
+pub mod config;
+pub mod cycle;
+pub mod differential;
+pub mod digits;
+pub mod error;
+pub mod fuzz;
+pub mod generate;
+pub mod graph;
+pub mod grid_n;
+pub mod image;
+pub mod input;
+pub mod json;
+pub mod leaderboard;
+pub mod linear;
+pub mod math;
+pub mod params;
+pub mod playback;
+pub mod range_set;
+pub mod registry;
+pub mod runner;
+pub mod stats;
+pub mod text;
+pub mod trace;
+pub mod watch;
+pub mod y2024;
+
+use error::{column_of, Error, Result};
+use input::read_input;
+
+pub fn get_columns_from_file<T: FromStr, const N: usize>(file_path: &str) -> Result<[Vec<T>; N]> {
     // Read file
-    let file_content = match fs::read_to_string(file_path) {
-        Ok(text) => text,
-        Err(err) => return Err(err.to_string()),
-    };
+    let file_content = read_input(file_path)?;
+
+    parse_columns(&file_content)
+}
 
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse_columns<T: FromStr, const N: usize>(content: &str) -> Result<[Vec<T>; N]> {
     // Create empty columns
     let mut columns = [const { Vec::new() }; N];
 
     // Parse each line
-    for (i, line) in file_content.lines().enumerate() {
+    for (i, line) in content.lines().enumerate() {
         // Split values by whitespace
         let parts = line.split_whitespace();
         let line_num = i + 1;
 
         // Validate - has N values separated by whitespace
         if parts.clone().count() != N {
-            return Err(format!(
-                "Error on line {line_num}. Must have {N} values separated by whitespace."
+            return Err(Error::at_line(
+                line_num,
+                format!("Must have {N} values separated by whitespace."),
             ));
         }
 
@@ -32,27 +64,35 @@ pub fn get_columns_from_file<T: FromStr, const N: usize>(
                     columns[j].push(value);
                 }
                 Err(_) => {
-                    return Err(format!("Invalid value '{raw_value}' on line {line_num}."));
+                    return Err(Error::at(
+                        line_num,
+                        column_of(line, raw_value),
+                        format!("Invalid value '{raw_value}'."),
+                    ));
                 }
             }
         }
     }
 
+    tracing::debug!(rows = columns[0].len(), columns = N, "parsed columns");
+
     Ok(columns)
 }
 
-pub fn get_rows_from_file<T: FromStr>(file_path: &str) -> Result<Vec<Vec<T>>, String> {
+pub fn get_rows_from_file<T: FromStr>(file_path: &str) -> Result<Vec<Vec<T>>> {
     // Read file
-    let file_content = match fs::read_to_string(file_path) {
-        Ok(text) => text,
-        Err(err) => return Err(err.to_string()),
-    };
+    let file_content = read_input(file_path)?;
 
+    parse_rows(&file_content)
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+pub fn parse_rows<T: FromStr>(content: &str) -> Result<Vec<Vec<T>>> {
     // Create rows
     let mut rows = Vec::new();
 
     // Parse each line
-    for (i, line) in file_content.lines().enumerate() {
+    for (i, line) in content.lines().enumerate() {
         let mut row = Vec::new();
 
         // Split values by whitespace
@@ -66,7 +106,11 @@ pub fn get_rows_from_file<T: FromStr>(file_path: &str) -> Result<Vec<Vec<T>>, St
                     row.push(value);
                 }
                 Err(_) => {
-                    return Err(format!("Invalid value '{raw_value}' on line {line_num}."));
+                    return Err(Error::at(
+                        line_num,
+                        column_of(line, raw_value),
+                        format!("Invalid value '{raw_value}'."),
+                    ));
                 }
             }
         }
@@ -75,6 +119,8 @@ pub fn get_rows_from_file<T: FromStr>(file_path: &str) -> Result<Vec<Vec<T>>, St
         rows.push(row);
     }
 
+    tracing::debug!(rows = rows.len(), "parsed rows");
+
     Ok(rows)
 }
 
@@ -171,7 +217,7 @@ impl Coordinates {
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
 pub enum Direction {
     Left,
     Right,
@@ -180,6 +226,40 @@ pub enum Direction {
 }
 
 impl Direction {
+    /// Clockwise.
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    /// Counterclockwise.
+    pub fn turn_left(self) -> Self {
+        self.turn_right().opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Right => Direction::Left,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+        }
+    }
+
+    /// 0..4 clockwise from `Up` - for arrays and bitmasks (`1 << index`) indexed by direction.
+    pub fn index(self) -> usize {
+        match self {
+            Direction::Up => 0,
+            Direction::Right => 1,
+            Direction::Down => 2,
+            Direction::Left => 3,
+        }
+    }
+
     pub fn step_distance(&self) -> Distance {
         match self {
             Direction::Left => (-1, 0),
@@ -197,6 +277,33 @@ impl Direction {
     }
 }
 
+/// Arrows `^v<>` and letters `UDLR` / `NSEW`.
+impl TryFrom<char> for Direction {
+    type Error = Error;
+
+    fn try_from(value: char) -> Result<Self> {
+        match value {
+            '^' | 'U' | 'N' => Ok(Direction::Up),
+            'v' | 'D' | 'S' => Ok(Direction::Down),
+            '<' | 'L' | 'W' => Ok(Direction::Left),
+            '>' | 'R' | 'E' => Ok(Direction::Right),
+            _ => Err(Error::new(format!("Invalid direction '{value}'."))),
+        }
+    }
+}
+
+/// Arrow `^v<>`.
+impl From<Direction> for char {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+        }
+    }
+}
+
 pub const DIRECTIONS: [Direction; 4] = [
     Direction::Left,
     Direction::Right,
@@ -0,0 +1,174 @@
+//! Exact solver of small integer linear systems `matrix * x = rhs` - no floating point tolerance, solutions are
+//! rationals in `i128`.
+//!
+//! Determinants use fraction-free (Bareiss) elimination, the unique solution follows from Cramer's rule. Arithmetic
+//! is checked - an intermediate value overflowing `i128` is an error instead of a wrong answer.
+
+use std::fmt;
+
+use crate::{
+    error::{Error, Result},
+    math::gcd,
+};
+
+/// Reduced fraction with a positive denominator.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    /// `None` if `denominator` is 0 or the reduced fraction doesn't fit (`i128::MIN` can't change sign).
+    pub fn new(numerator: i128, denominator: i128) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let divisor = gcd(numerator, denominator)?;
+        let (mut numerator, mut denominator) = (numerator / divisor, denominator / divisor);
+        if denominator < 0 {
+            (numerator, denominator) = (numerator.checked_neg()?, denominator.checked_neg()?);
+        }
+
+        Some(Self {
+            numerator,
+            denominator,
+        })
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    /// Value if it's an integer.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.denominator {
+            1 => write!(f, "{}", self.numerator),
+            _ => write!(f, "{}/{}", self.numerator, self.denominator),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Solution<const N: usize> {
+    Unique([Rational; N]),
+    /// Inconsistent system
+    None,
+    /// Singular consistent system - solutions form a line (or more)
+    Infinite,
+}
+
+fn overflow() -> Error {
+    Error::new("Linear system arithmetic overflowed i128.")
+}
+
+/// Solve `matrix * x = rhs`, `matrix` is row-major.
+pub fn solve<const N: usize>(matrix: [[i128; N]; N], rhs: [i128; N]) -> Result<Solution<N>> {
+    let determinant = determinant(matrix)?;
+
+    if determinant != 0 {
+        // Cramer's rule - x_i = det(matrix with column i replaced by rhs) / det(matrix)
+        let mut solution = [Rational {
+            numerator: 0,
+            denominator: 1,
+        }; N];
+        for (column, value) in solution.iter_mut().enumerate() {
+            let mut replaced = matrix;
+            for (row, replaced_row) in replaced.iter_mut().enumerate() {
+                replaced_row[column] = rhs[row];
+            }
+            *value =
+                Rational::new(self::determinant(replaced)?, determinant).ok_or_else(overflow)?;
+        }
+
+        return Ok(Solution::Unique(solution));
+    }
+
+    // Singular - consistent if the augmented matrix has the same rank
+    let rows: Vec<Vec<i128>> = matrix
+        .iter()
+        .zip(rhs)
+        .map(|(row, rhs)| row.iter().copied().chain([rhs]).collect())
+        .collect();
+    let echelon = echelon_form(rows)?;
+    let is_inconsistent = echelon
+        .iter()
+        .any(|row| row[..N].iter().all(|&value| value == 0) && row[N] != 0);
+
+    match is_inconsistent {
+        true => Ok(Solution::None),
+        false => Ok(Solution::Infinite),
+    }
+}
+
+/// Determinant of a square matrix (row-major).
+pub fn determinant<const N: usize>(matrix: [[i128; N]; N]) -> Result<i128> {
+    if N == 0 {
+        return Ok(1);
+    }
+
+    let rows: Vec<Vec<i128>> = matrix.iter().map(|row| row.to_vec()).collect();
+    let mut sign = 1;
+    let echelon = bareiss(rows, &mut sign)?;
+
+    // Full rank => the last pivot is the determinant (up to the row swaps)
+    echelon[N - 1][N - 1].checked_mul(sign).ok_or_else(overflow)
+}
+
+/// Row echelon form (fraction-free), rows of zeros last.
+fn echelon_form(rows: Vec<Vec<i128>>) -> Result<Vec<Vec<i128>>> {
+    bareiss(rows, &mut 1)
+}
+
+/// Bareiss elimination - each entry stays an integer (a minor of the original matrix), so the divisions are exact.
+/// Columns without a pivot are skipped. `sign` is negated on every row swap.
+fn bareiss(mut rows: Vec<Vec<i128>>, sign: &mut i128) -> Result<Vec<Vec<i128>>> {
+    let columns = rows.first().map_or(0, Vec::len);
+    let mut pivot_row = 0;
+    let mut previous_pivot = 1;
+
+    for column in 0..columns {
+        if pivot_row == rows.len() {
+            break;
+        }
+        let Some(found) = (pivot_row..rows.len()).find(|&row| rows[row][column] != 0) else {
+            continue;
+        };
+        if found != pivot_row {
+            rows.swap(found, pivot_row);
+            *sign = -*sign;
+        }
+
+        let (upper, lower) = rows.split_at_mut(pivot_row + 1);
+        let pivot_values = &upper[pivot_row];
+        let pivot = pivot_values[column];
+        for row in lower {
+            let factor = row[column];
+            for (value, pivot_value) in row.iter_mut().zip(pivot_values).skip(column) {
+                // (pivot * value - factor * pivot row value) / previous pivot
+                let eliminated = pivot
+                    .checked_mul(*value)
+                    .zip(factor.checked_mul(*pivot_value))
+                    .and_then(|(a, b)| a.checked_sub(b))
+                    .ok_or_else(overflow)?;
+                *value = eliminated / previous_pivot;
+            }
+        }
+
+        previous_pivot = pivot;
+        pivot_row += 1;
+    }
+
+    Ok(rows)
+}
@@ -0,0 +1,186 @@
+//! Number theory over signed integers - gcd, lcm, extended Euclid, modular inverse and the Chinese remainder theorem.
+//!
+//! All functions are overflow-safe - `None` if the result (or a required intermediate value) doesn't fit the type,
+//! e.g. `gcd(i64::MIN, 0)` is 2^63. Intermediate products are computed modulo the modulus, so anything whose result
+//! fits works even for `i128`.
+
+use std::fmt::Debug;
+
+/// Signed integer types the functions work with.
+pub trait Integer: Copy + Ord + Debug {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+    fn checked_sub(self, other: Self) -> Option<Self>;
+    fn checked_mul(self, other: Self) -> Option<Self>;
+    fn checked_div(self, other: Self) -> Option<Self>;
+    fn checked_rem_euclid(self, other: Self) -> Option<Self>;
+    fn checked_abs(self) -> Option<Self>;
+    fn checked_neg(self) -> Option<Self>;
+    /// `MIN % -1` is 0 instead of overflowing.
+    fn wrapping_rem(self, other: Self) -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$t>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$t>::checked_sub(self, other)
+                }
+
+                fn checked_mul(self, other: Self) -> Option<Self> {
+                    <$t>::checked_mul(self, other)
+                }
+
+                fn checked_div(self, other: Self) -> Option<Self> {
+                    <$t>::checked_div(self, other)
+                }
+
+                fn checked_rem_euclid(self, other: Self) -> Option<Self> {
+                    <$t>::checked_rem_euclid(self, other)
+                }
+
+                fn checked_abs(self) -> Option<Self> {
+                    <$t>::checked_abs(self)
+                }
+
+                fn checked_neg(self) -> Option<Self> {
+                    <$t>::checked_neg(self)
+                }
+
+                fn wrapping_rem(self, other: Self) -> Self {
+                    <$t>::wrapping_rem(self, other)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize);
+
+/// Greatest common divisor, never negative. `gcd(0, 0)` is 0.
+pub fn gcd<T: Integer>(a: T, b: T) -> Option<T> {
+    let (mut a, mut b) = (a, b);
+    while b != T::ZERO {
+        (a, b) = (b, a.wrapping_rem(b));
+    }
+
+    a.checked_abs()
+}
+
+/// Least common multiple, never negative. 0 if either number is 0.
+pub fn lcm<T: Integer>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+
+    a.checked_div(gcd(a, b)?)?.checked_mul(b)?.checked_abs()
+}
+
+/// Extended Euclidean algorithm - `(gcd, x, y)` with `a * x + b * y = gcd`, `gcd` is never negative.
+/// The coefficients are the minimal ones (`|x| <= |b / gcd|`, `|y| <= |a / gcd|`).
+pub fn extended_gcd<T: Integer>(a: T, b: T) -> Option<(T, T, T)> {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (T::ONE, T::ZERO);
+    let (mut old_y, mut y) = (T::ZERO, T::ONE);
+
+    while r != T::ZERO {
+        let quotient = old_r.checked_div(r)?;
+        (old_r, r) = (r, old_r.checked_sub(quotient.checked_mul(r)?)?);
+        (old_x, x) = (x, old_x.checked_sub(quotient.checked_mul(x)?)?);
+        (old_y, y) = (y, old_y.checked_sub(quotient.checked_mul(y)?)?);
+    }
+
+    match old_r < T::ZERO {
+        true => Some((
+            old_r.checked_neg()?,
+            old_x.checked_neg()?,
+            old_y.checked_neg()?,
+        )),
+        false => Some((old_r, old_x, old_y)),
+    }
+}
+
+/// `a * b mod modulus` in `0..modulus` without overflowing the product. `modulus` must be positive.
+pub fn mul_mod<T: Integer>(a: T, b: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+
+    let mut a = a.checked_rem_euclid(modulus)?;
+    let mut b = b.checked_rem_euclid(modulus)?;
+    if let Some(product) = a.checked_mul(b) {
+        return product.checked_rem_euclid(modulus);
+    }
+
+    // Double and add - both operands stay below `modulus`
+    let add_mod = |a: T, b: T| match a >= modulus.checked_sub(b)? {
+        true => a.checked_sub(modulus.checked_sub(b)?),
+        false => a.checked_add(b),
+    };
+    let two = T::ONE.checked_add(T::ONE)?;
+    let mut product = T::ZERO;
+    while b > T::ZERO {
+        if b.checked_rem_euclid(two)? == T::ONE {
+            product = add_mod(product, a)?;
+        }
+        a = add_mod(a, a)?;
+        b = b.checked_div(two)?;
+    }
+
+    Some(product)
+}
+
+/// Inverse of `a` modulo `modulus` in `0..modulus`, `None` if they aren't coprime or `modulus` isn't positive.
+pub fn mod_inverse<T: Integer>(a: T, modulus: T) -> Option<T> {
+    if modulus <= T::ZERO {
+        return None;
+    }
+
+    let (gcd, x, _) = extended_gcd(a.checked_rem_euclid(modulus)?, modulus)?;
+    match gcd == T::ONE {
+        true => x.checked_rem_euclid(modulus),
+        false => None,
+    }
+}
+
+/// Chinese remainder theorem - solution of `x ≡ residue (mod modulus)` for all `(residue, modulus)` pairs.
+/// Returns `(x, lcm of the moduli)` with `x` in `0..lcm` - all solutions are `x + k * lcm`.
+///
+/// Moduli don't have to be coprime. `None` if there is no solution, a modulus isn't positive or the lcm overflows.
+pub fn crt<T: Integer>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut solution = (T::ZERO, T::ONE);
+
+    for &(residue, modulus) in congruences {
+        if modulus <= T::ZERO {
+            return None;
+        }
+        let (x, lcm_so_far) = solution;
+        let residue = residue.checked_rem_euclid(modulus)?;
+
+        // x + lcm_so_far * k ≡ residue (mod modulus) => lcm_so_far / g * k ≡ (residue - x) / g (mod modulus / g)
+        let gcd = gcd(lcm_so_far, modulus)?;
+        let difference = residue.checked_sub(x)?;
+        if difference.checked_rem_euclid(gcd)? != T::ZERO {
+            return None;
+        }
+        let reduced_modulus = modulus.checked_div(gcd)?;
+        let inverse = mod_inverse(lcm_so_far.checked_div(gcd)?, reduced_modulus)?;
+        let k = mul_mod(difference.checked_div(gcd)?, inverse, reduced_modulus)?;
+
+        // k < modulus / g => the new x is below the new lcm
+        let lcm = lcm_so_far.checked_mul(reduced_modulus)?;
+        solution = (x.checked_add(lcm_so_far.checked_mul(k)?)?, lcm);
+    }
+
+    Some(solution)
+}
@@ -0,0 +1,108 @@
+//! Puzzle parameters - values that are fixed in the puzzle text but differ between the real input and the examples
+//! (grid size, iteration count, ...). Solvers fall back to the real input's values for parameters that are not set.
+//!
+//! Parameters of an input are read from its sidecar file (same path, `.params` extension), `#` starts a comment:
+//! ```text
+//! # resources/2024_14_example.params
+//! x_len = 11
+//! y_len = 7
+//! ```
+//! and can be overridden from the command line, e.g. `--params x_len=11,y_len=7`.
+
+use std::{collections::BTreeMap, fs, io, path::Path, str::FromStr};
+
+use crate::error::{column_of, Error, Result};
+
+pub const SIDECAR_EXTENSION: &str = "params";
+
+#[derive(Clone, Default, PartialEq, Debug)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    /// Load parameters of the input from its sidecar file. Missing file => no parameters.
+    pub fn load_for_input(input_path: &str) -> Result<Self> {
+        let sidecar_path = sidecar_path(input_path);
+
+        match fs::read_to_string(&sidecar_path) {
+            Ok(content) => Self::parse(&content).map_err(|err| err.with_file(&sidecar_path)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => {
+                Err(Error::new(format!("Failed to read file: {err}")).with_file(&sidecar_path))
+            }
+        }
+    }
+
+    /// Parse `name = value` lines.
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut params = Self::default();
+
+        for (i, line) in content.lines().enumerate() {
+            let line_num = i + 1;
+            let line = line.split('#').next().unwrap_or_default();
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (name, value) = line
+                .split_once('=')
+                .ok_or_else(|| Error::at_line(line_num, "Expected `name = value`."))?;
+            let (name, value) = (name.trim(), value.trim());
+
+            if name.is_empty() || value.is_empty() {
+                return Err(Error::at_line(line_num, "Expected `name = value`."));
+            }
+            if params.values.contains_key(name) {
+                return Err(Error::at(
+                    line_num,
+                    column_of(line, name),
+                    format!("Duplicate parameter '{name}'."),
+                ));
+            }
+
+            params.set(name, value);
+        }
+
+        Ok(params)
+    }
+
+    /// Parse comma separated `name=value` pairs, e.g. `x_len=11,y_len=7`.
+    pub fn parse_overrides(overrides: &str) -> Result<Self> {
+        Self::parse(&overrides.replace(',', "\n"))
+            .map_err(|err| Error::new(format!("Invalid parameters '{overrides}': {err}")))
+    }
+
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_string(), value.to_string());
+    }
+
+    /// Parameters of `other` take precedence.
+    pub fn merged(mut self, other: &Params) -> Self {
+        self.values.extend(other.values.clone());
+        self
+    }
+
+    /// Parsed parameter value, `default` if not set.
+    pub fn get<T: FromStr>(&self, name: &str, default: T) -> Result<T> {
+        match self.values.get(name) {
+            Some(value) => value
+                .parse()
+                .map_err(|_| Error::new(format!("Invalid value '{value}' of parameter '{name}'."))),
+            None => Ok(default),
+        }
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.values.keys().map(|name| name.as_str())
+    }
+}
+
+/// Path to the sidecar file of the input, e.g. `./resources/2024_14_example.params`.
+pub fn sidecar_path(input_path: &str) -> String {
+    Path::new(input_path)
+        .with_extension(SIDECAR_EXTENSION)
+        .to_string_lossy()
+        .to_string()
+}
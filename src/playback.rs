@@ -0,0 +1,350 @@
+//! Interactive terminal playback of recorded simulations (see `crate::animation`).
+//! Grid is redrawn in place using ANSI escape codes. Keys are read from stdin - single key presses if `stty` can switch
+//! the terminal to non-canonical mode, otherwise line by line (confirmed by Enter).
+
+use std::{
+    io::{self, IsTerminal, Read, Write},
+    process::{Command, Stdio},
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    animation::{Animation, Recording, Snapshot},
+    error::{Error, Result},
+    params::Params,
+};
+
+/// Snapshots recorded at once - the simulation is replayed from its start for every batch.
+const BATCH_SIZE: usize = 64;
+const MAX_FPS: u32 = 200;
+
+const HELP: &str = "space play/pause  n/→ step  b/← back  <number>g jump  +/- speed  q quit";
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Key {
+    PlayPause,
+    Forward,
+    Back,
+    Digit(u8),
+    Jump,
+    Faster,
+    Slower,
+    Quit,
+}
+
+impl Key {
+    /// Keys pressed, unknown ones are skipped. Arrow keys are the escape sequences `ESC [ C` and `ESC [ D`.
+    pub fn parse(bytes: &[u8]) -> Vec<Key> {
+        let mut keys = vec![];
+        let mut bytes = bytes.iter();
+
+        while let Some(byte) = bytes.next() {
+            let key = match byte {
+                0x1B => match (bytes.next(), bytes.next()) {
+                    (Some(b'['), Some(b'C')) => Key::Forward,
+                    (Some(b'['), Some(b'D')) => Key::Back,
+                    _ => continue,
+                },
+                b' ' | b'p' => Key::PlayPause,
+                b'n' | b'l' | b'.' => Key::Forward,
+                b'b' | b'h' | b',' => Key::Back,
+                b'0'..=b'9' => Key::Digit(byte - b'0'),
+                b'g' => Key::Jump,
+                b'+' | b'=' => Key::Faster,
+                b'-' => Key::Slower,
+                b'q' | 0x03 | 0x04 => Key::Quit, // Ctrl-C and Ctrl-D too, signals are off
+                _ => continue,
+            };
+            keys.push(key);
+        }
+
+        keys
+    }
+}
+
+/// Playback state - current snapshot, play/pause and speed.
+pub struct Player<'a> {
+    animation: &'a Animation,
+    input: &'a str,
+    params: &'a Params,
+    /// Consecutive iterations around the current one
+    snapshots: Vec<Snapshot>,
+    current: usize,
+    /// Known once the simulation ended
+    last_iteration: Option<usize>,
+    is_playing: bool,
+    fps: u32,
+    jump_digits: String,
+}
+
+impl<'a> Player<'a> {
+    pub fn new(
+        animation: &'a Animation,
+        input: &'a str,
+        params: &'a Params,
+        start: usize,
+        fps: u32,
+    ) -> Result<Self> {
+        let mut player = Self {
+            animation,
+            input,
+            params,
+            snapshots: vec![],
+            current: 0,
+            last_iteration: None,
+            is_playing: false,
+            fps: fps.clamp(1, MAX_FPS),
+            jump_digits: String::new(),
+        };
+        player.go_to(start)?;
+
+        Ok(player)
+    }
+
+    pub fn snapshot(&self) -> &Snapshot {
+        &self.snapshots[self.current]
+    }
+
+    pub fn iteration(&self) -> usize {
+        self.snapshot().iteration
+    }
+
+    pub fn is_playing(&self) -> bool {
+        self.is_playing
+    }
+
+    /// Delay between frames while playing.
+    pub fn frame_delay(&self) -> Duration {
+        Duration::from_secs(1) / self.fps
+    }
+
+    /// Returns `false` if the playback should end.
+    pub fn handle(&mut self, key: Key) -> Result<bool> {
+        match key {
+            Key::PlayPause => self.is_playing = !self.is_playing,
+            Key::Forward => {
+                self.is_playing = false;
+                self.go_to(self.iteration() + 1)?;
+            }
+            Key::Back => {
+                self.is_playing = false;
+                self.go_to(self.iteration().saturating_sub(1))?;
+            }
+            Key::Digit(digit) => self.jump_digits.push(char::from(b'0' + digit)),
+            Key::Jump => {
+                // Too many digits => stay
+                if let Ok(iteration) = self.jump_digits.parse() {
+                    self.go_to(iteration)?;
+                }
+                self.jump_digits.clear();
+            }
+            Key::Faster => self.fps = (self.fps * 2).min(MAX_FPS),
+            Key::Slower => self.fps = (self.fps / 2).max(1),
+            Key::Quit => return Ok(false),
+        }
+
+        Ok(true)
+    }
+
+    /// Next frame while playing, pauses at the end of the simulation.
+    pub fn tick(&mut self) -> Result<()> {
+        if !self.is_playing {
+            return Ok(());
+        }
+
+        let iteration = self.iteration();
+        self.go_to(iteration + 1)?;
+        if self.iteration() == iteration {
+            self.is_playing = false;
+        }
+
+        Ok(())
+    }
+
+    /// Snapshot of the iteration, the final one if the simulation ends before.
+    fn go_to(&mut self, iteration: usize) -> Result<()> {
+        let iteration = self
+            .last_iteration
+            .map_or(iteration, |last| iteration.min(last));
+
+        if let Some(index) = self
+            .snapshots
+            .iter()
+            .position(|snapshot| snapshot.iteration == iteration)
+        {
+            self.current = index;
+            return Ok(());
+        }
+
+        // Batch in the direction of movement
+        let start = match self.snapshots.is_empty() || iteration > self.iteration() {
+            true => iteration,
+            false => iteration.saturating_sub(BATCH_SIZE - 1),
+        };
+        let recording = Recording {
+            start,
+            step: 1,
+            frames: BATCH_SIZE,
+        };
+        let snapshots = (self.animation.record)(self.input, self.params, &recording)?;
+
+        let last = snapshots
+            .last()
+            .ok_or_else(|| Error::new("Simulation recorded no snapshots."))?;
+        if last.iteration < iteration || snapshots.len() < BATCH_SIZE {
+            self.last_iteration = Some(last.iteration);
+        }
+
+        self.current = snapshots
+            .iter()
+            .rposition(|snapshot| snapshot.iteration <= iteration)
+            .unwrap_or(0);
+        self.snapshots = snapshots;
+
+        Ok(())
+    }
+
+    /// Frame drawn over the previous one - grid (coloured by the animation if `colour`), status and help line.
+    pub fn render(&self, colour: bool) -> String {
+        let snapshot = self.snapshot();
+        let mut frame = String::from("\x1b[H");
+
+        for row in snapshot.grid.get_rows() {
+            let mut previous = None;
+            for point in row {
+                if colour {
+                    let rgb = (self.animation.colour)(point);
+                    if previous != Some(rgb) {
+                        frame.push_str(&format!("\x1b[38;2;{};{};{}m", rgb.0, rgb.1, rgb.2));
+                        previous = Some(rgb);
+                    }
+                }
+                frame.push(*point);
+            }
+            if colour {
+                frame.push_str("\x1b[0m");
+            }
+            frame.push_str("\x1b[K\n");
+        }
+
+        let last = self
+            .last_iteration
+            .map_or(String::new(), |last| format!("/{last}"));
+        let state = match self.is_playing {
+            true => "playing",
+            false => "paused",
+        };
+        frame.push_str(&format!(
+            "{} day {} {} | iteration {}{last}",
+            self.animation.year, self.animation.day, self.animation.part, snapshot.iteration
+        ));
+        for (name, value) in &snapshot.metrics {
+            frame.push_str(&format!(" | {name} {value}"));
+        }
+        frame.push_str(&format!(" | {state} {} fps\x1b[K\n", self.fps));
+
+        match self.jump_digits.is_empty() {
+            true => frame.push_str(HELP),
+            false => frame.push_str(&format!("jump to {} (g)", self.jump_digits)),
+        }
+        frame.push_str("\x1b[K\x1b[J");
+
+        frame
+    }
+}
+
+/// Play the animation in the terminal until quit.
+pub fn play(
+    animation: &Animation,
+    input: &str,
+    params: &Params,
+    start: usize,
+    fps: u32,
+) -> Result<()> {
+    let mut player = Player::new(animation, input, params, start, fps)?;
+    let colour = io::stdout().is_terminal();
+
+    let (sender, keys) = mpsc::channel();
+    thread::spawn(move || {
+        let mut buffer = [0; 16];
+        let mut stdin = io::stdin();
+        loop {
+            let keys = match stdin.read(&mut buffer) {
+                Ok(0) | Err(_) => vec![Key::Quit],
+                Ok(count) => Key::parse(&buffer[..count]),
+            };
+            if keys.iter().any(|key| sender.send(*key).is_err()) || keys.contains(&Key::Quit) {
+                return;
+            }
+        }
+    });
+
+    let _terminal_mode = TerminalMode::enable();
+    let mut stdout = io::stdout();
+    let write_error = |err: io::Error| Error::new(format!("Failed to write to terminal: {err}"));
+
+    // Hide cursor and clear the screen, undone even on error
+    write!(stdout, "\x1b[?25l\x1b[2J").map_err(write_error)?;
+    let result = (|| loop {
+        write!(stdout, "{}", player.render(colour)).map_err(write_error)?;
+        stdout.flush().map_err(write_error)?;
+
+        let key = match player.is_playing() {
+            true => keys.recv_timeout(player.frame_delay()),
+            false => keys.recv().map_err(|_| RecvTimeoutError::Disconnected),
+        };
+        match key {
+            Ok(key) => {
+                if !player.handle(key)? {
+                    return Ok(());
+                }
+            }
+            Err(RecvTimeoutError::Timeout) => player.tick()?,
+            Err(RecvTimeoutError::Disconnected) => return Ok(()),
+        }
+    })();
+    writeln!(stdout, "\x1b[?25h").map_err(write_error)?;
+
+    result
+}
+
+/// Single key input without echo, the original terminal settings are restored on drop.
+struct TerminalMode {
+    saved: String,
+}
+
+impl TerminalMode {
+    /// `None` if stdin is not a terminal or `stty` failed.
+    fn enable() -> Option<Self> {
+        if !io::stdin().is_terminal() {
+            return None;
+        }
+
+        let saved = stty(&["-g"])?.trim().to_string();
+        stty(&["-icanon", "-echo", "-isig", "min", "1"])?;
+
+        Some(Self { saved })
+    }
+}
+
+impl Drop for TerminalMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+/// Run `stty` on the terminal, its output if successful.
+fn stty(args: &[&str]) -> Option<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()
+        .ok()?;
+
+    output
+        .status
+        .success()
+        .then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
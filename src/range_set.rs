@@ -0,0 +1,153 @@
+//! Set of values stored as disjoint half-open ranges - range arithmetic like free disk space or covered intervals.
+//!
+//! Overlapping and adjacent ranges are merged on insertion, so iteration yields maximal ranges in ascending order.
+
+use std::{
+    collections::BTreeMap,
+    ops::{Range, Sub},
+};
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RangeSet<T> {
+    /// Start => end of each range
+    ranges: BTreeMap<T, T>,
+}
+
+impl<T> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self {
+            ranges: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Copy + Ord> RangeSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add all values of `range`, empty ranges are ignored.
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+        let (mut start, mut end) = (range.start, range.end);
+
+        // Range starting before, overlapping or adjacent
+        if let Some((&previous_start, &previous_end)) = self.ranges.range(..=start).next_back() {
+            if previous_end >= start {
+                start = previous_start;
+                end = end.max(previous_end);
+            }
+        }
+
+        // Ranges starting within (or right after) the new one are absorbed
+        let absorbed: Vec<(T, T)> = self
+            .ranges
+            .range(start..=end)
+            .map(|(&start, &end)| (start, end))
+            .collect();
+        for (absorbed_start, absorbed_end) in absorbed {
+            self.ranges.remove(&absorbed_start);
+            end = end.max(absorbed_end);
+        }
+
+        self.ranges.insert(start, end);
+    }
+
+    /// Remove all values of `range`, ranges partially covered are shortened or split in two.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Range starting before `range` - keep the part before, re-insert the part after below
+        if let Some((&previous_start, &previous_end)) = self.ranges.range(..range.start).next_back()
+        {
+            if previous_end > range.start {
+                self.ranges.insert(previous_start, range.start);
+                if previous_end > range.end {
+                    self.ranges.insert(range.end, previous_end);
+                }
+            }
+        }
+
+        let covered: Vec<(T, T)> = self
+            .ranges
+            .range(range.clone())
+            .map(|(&start, &end)| (start, end))
+            .collect();
+        for (covered_start, covered_end) in covered {
+            self.ranges.remove(&covered_start);
+            if covered_end > range.end {
+                self.ranges.insert(range.end, covered_end);
+            }
+        }
+    }
+
+    /// Split the set at `at` - values `>= at` are moved to the returned set, a range containing `at` is cut in two.
+    pub fn split_off(&mut self, at: T) -> Self {
+        let mut upper = self.ranges.split_off(&at);
+
+        if let Some((_, end)) = self.ranges.last_key_value() {
+            if *end > at {
+                upper.insert(at, *end);
+                self.ranges.last_entry().unwrap().insert(at);
+            }
+        }
+
+        Self { ranges: upper }
+    }
+
+    /// Add all values of `other` (union).
+    pub fn merge(&mut self, other: &Self) {
+        for range in other.iter() {
+            self.insert(range);
+        }
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        self.range_containing(value).is_some()
+    }
+
+    /// Maximal range containing `value`.
+    pub fn range_containing(&self, value: T) -> Option<Range<T>> {
+        self.ranges
+            .range(..=value)
+            .next_back()
+            .filter(|(_, &end)| end > value)
+            .map(|(&start, &end)| start..end)
+    }
+
+    /// Maximal ranges in ascending order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = Range<T>> + '_ {
+        self.ranges.iter().map(|(&start, &end)| start..end)
+    }
+
+    /// Number of (maximal) ranges.
+    pub fn len(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> RangeSet<T> {
+    /// First (lowest) range at least `length` long - e.g. free space a file fits in.
+    pub fn first_fit(&self, length: T) -> Option<Range<T>> {
+        self.iter().find(|range| range.end - range.start >= length)
+    }
+}
+
+impl<T: Copy + Ord> FromIterator<Range<T>> for RangeSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(ranges: I) -> Self {
+        let mut set = Self::new();
+        for range in ranges {
+            set.insert(range);
+        }
+
+        set
+    }
+}
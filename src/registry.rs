@@ -0,0 +1,138 @@
+//! Registry of all solutions, so that they can be run by year and day instead of by a dedicated binary.
+
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+    time::Instant,
+};
+
+use crate::{error::Error, error::Result, params::Params, y2024};
+
+/// Solver of one puzzle part. Answer is converted to string so that all parts share the same signature.
+pub type PartSolver = fn(&str, &Params) -> Result<String>;
+
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part_one: PartSolver,
+    pub part_two: PartSolver,
+    /// Names of the parameters the solution accepts, see `crate::params`.
+    pub parameters: &'static [&'static str],
+}
+
+impl Solution {
+    /// Solve part of the puzzle. Panics in the solver are caught and reported as `Outcome::Panicked`.
+    /// The panic hook is left alone, so it still prints the panic - replacing it is up to the caller.
+    pub fn solve(&self, part: Part, input: &str, params: &Params) -> Outcome {
+        let solver = match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        };
+
+        let _span =
+            tracing::debug_span!("solve", year = self.year, day = self.day, %part).entered();
+        let start = Instant::now();
+
+        let outcome = match panic::catch_unwind(AssertUnwindSafe(|| solver(input, params))) {
+            Ok(Ok(answer)) => Outcome::Answer(answer),
+            Ok(Err(err)) => Outcome::Failed(err),
+            Err(payload) => {
+                let message = if let Some(message) = payload.downcast_ref::<&str>() {
+                    message.to_string()
+                } else if let Some(message) = payload.downcast_ref::<String>() {
+                    message.clone()
+                } else {
+                    "unknown panic".to_string()
+                };
+                Outcome::Panicked(message)
+            }
+        };
+
+        tracing::debug!(%outcome, elapsed = ?start.elapsed(), "solved");
+
+        outcome
+    }
+
+    /// Parameters of the input file (its sidecar file) on top of `defaults`, with `overrides` applied.
+    /// See `crate::params`.
+    pub fn params_for_input(
+        &self,
+        input_path: &str,
+        defaults: &Params,
+        overrides: &Params,
+    ) -> Result<Params> {
+        let params = defaults
+            .clone()
+            .merged(&Params::load_for_input(input_path)?)
+            .merged(overrides);
+        self.check_params(&params)?;
+
+        Ok(params)
+    }
+
+    /// Reject parameters the solution doesn't know - most likely a typo.
+    pub fn check_params(&self, params: &Params) -> Result<()> {
+        match params.names().find(|name| !self.parameters.contains(name)) {
+            Some(name) => Err(Error::new(format!(
+                "Unknown parameter '{name}' for {}/{:02}, expected one of: {}.",
+                self.year,
+                self.day,
+                self.parameters.join(", ")
+            ))),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Part {
+    One,
+    Two,
+}
+
+pub const PARTS: [Part; 2] = [Part::One, Part::Two];
+
+impl Part {
+    /// 1 or 2 - as in the answers file, the statistics ledger and the TSV report.
+    pub fn number(self) -> usize {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "Part One"),
+            Part::Two => write!(f, "Part Two"),
+        }
+    }
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Outcome {
+    Answer(String),
+    Failed(Error),
+    Panicked(String),
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Answer(answer) => write!(f, "{answer}"),
+            Outcome::Failed(err) => write!(f, "error: {err}"),
+            Outcome::Panicked(message) => write!(f, "panicked: {message}"),
+        }
+    }
+}
+
+/// All registered solutions.
+pub fn solutions() -> impl Iterator<Item = &'static Solution> {
+    y2024::SOLUTIONS.iter()
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static Solution> {
+    solutions().find(|solution| solution.year == year && solution.day == day)
+}
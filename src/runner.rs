@@ -0,0 +1,241 @@
+//! Running a solution on one or more inputs and reporting the answers side by side.
+
+use std::{
+    fmt::Write,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{input_name, AnswerStore},
+    config::{Config, OutputFormat},
+    input::read_input,
+    params::Params,
+    registry::{Outcome, Part, Solution, PARTS},
+};
+
+pub struct InputReport {
+    pub path: String,
+    /// Outcome of each part, empty if the input or its parameters could not be read.
+    pub parts: Vec<PartReport>,
+    pub read_error: Option<String>,
+}
+
+pub struct PartReport {
+    pub part: Part,
+    pub outcome: Outcome,
+    pub check: Check,
+    pub elapsed: Duration,
+}
+
+/// Comparison of the answer with the recorded (known) answer.
+#[derive(PartialEq, Debug)]
+pub enum Check {
+    Unknown,
+    Correct,
+    Incorrect { expected: String },
+}
+
+impl InputReport {
+    /// Whether the input needs attention - unreadable, failed, panicked or disagrees with the recorded answer.
+    pub fn is_flagged(&self) -> bool {
+        self.read_error.is_some()
+            || self.parts.iter().any(|part_report| {
+                !matches!(part_report.outcome, Outcome::Answer(_))
+                    || matches!(part_report.check, Check::Incorrect { .. })
+            })
+    }
+}
+
+/// Solve both parts for each input - with the input's parameters on top of the configured ones and `overrides` applied.
+/// Inputs are split between `config.threads` threads, reports keep the order of `paths`.
+pub fn run_inputs(
+    solution: &Solution,
+    paths: &[String],
+    store: &AnswerStore,
+    config: &Config,
+    overrides: &Params,
+) -> Vec<InputReport> {
+    let defaults = config.params(solution.year, solution.day);
+    let run = |path: &String| run_input(solution, path, store, &defaults, overrides);
+
+    if config.threads <= 1 || paths.len() <= 1 {
+        return paths.iter().map(run).collect();
+    }
+
+    let chunk_size = paths.len().div_ceil(config.threads);
+    thread::scope(|scope| {
+        let handles: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| scope.spawn(move || chunk.iter().map(run).collect::<Vec<_>>()))
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().expect("Solver panics are caught."))
+            .collect()
+    })
+}
+
+fn run_input(
+    solution: &Solution,
+    path: &str,
+    store: &AnswerStore,
+    defaults: &Params,
+    overrides: &Params,
+) -> InputReport {
+    let loaded = read_input(path)
+        .and_then(|input| Ok((input, solution.params_for_input(path, defaults, overrides)?)));
+    let (input, params) = match loaded {
+        Ok(val) => val,
+        Err(err) => {
+            return InputReport {
+                path: path.to_string(),
+                parts: vec![],
+                read_error: Some(err.to_string()),
+            };
+        }
+    };
+
+    let name = input_name(path);
+    let mut parts = vec![];
+
+    for part in PARTS {
+        let start = Instant::now();
+        let outcome = solution.solve(part, &input, &params);
+        let elapsed = start.elapsed();
+        let check = match (
+            &outcome,
+            store.get(solution.year, solution.day, &name, part),
+        ) {
+            (_, None) => Check::Unknown,
+            (Outcome::Answer(answer), Some(expected)) if answer == expected => Check::Correct,
+            (_, Some(expected)) => Check::Incorrect {
+                expected: expected.to_string(),
+            },
+        };
+
+        parts.push(PartReport {
+            part,
+            outcome,
+            check,
+            elapsed,
+        });
+    }
+
+    InputReport {
+        path: path.to_string(),
+        parts,
+        read_error: None,
+    }
+}
+
+/// Report in the configured output format.
+pub fn format_report(reports: &[InputReport], format: OutputFormat) -> String {
+    match format {
+        OutputFormat::Table => format_table(reports),
+        OutputFormat::Tsv => format_tsv(reports),
+    }
+}
+
+/// One line per input and part: `path  part  status  answer  expected` (tab separated), header first.
+/// Status is `ok`, `unknown`, `incorrect`, `failed`, `panicked` or `unreadable`.
+fn format_tsv(reports: &[InputReport]) -> String {
+    let mut tsv = "path\tpart\tstatus\tanswer\texpected\n".to_string();
+
+    for report in reports {
+        if let Some(err) = &report.read_error {
+            writeln!(tsv, "{}\t\tunreadable\t{err}\t", report.path).unwrap();
+            continue;
+        }
+
+        for part_report in &report.parts {
+            let part = part_report.part.number();
+            let (status, answer) = match (&part_report.outcome, &part_report.check) {
+                (Outcome::Failed(err), _) => ("failed", err.to_string()),
+                (Outcome::Panicked(message), _) => ("panicked", message.clone()),
+                (Outcome::Answer(answer), Check::Unknown) => ("unknown", answer.clone()),
+                (Outcome::Answer(answer), Check::Correct) => ("ok", answer.clone()),
+                (Outcome::Answer(answer), Check::Incorrect { .. }) => ("incorrect", answer.clone()),
+            };
+            let expected = match &part_report.check {
+                Check::Incorrect { expected } => expected.as_str(),
+                _ => "",
+            };
+
+            // Tabs and newlines would break the format
+            let answer = answer.replace(['\t', '\n'], " ");
+            writeln!(
+                tsv,
+                "{}\t{part}\t{status}\t{answer}\t{expected}",
+                report.path
+            )
+            .unwrap();
+        }
+    }
+
+    tsv
+}
+
+/// Table with one row per input and one column per part. Flagged inputs are marked with `!`.
+fn format_table(reports: &[InputReport]) -> String {
+    let mut rows: Vec<[String; 4]> = vec![[
+        String::new(),
+        "Input".to_string(),
+        Part::One.to_string(),
+        Part::Two.to_string(),
+    ]];
+
+    for report in reports {
+        let flag = if report.is_flagged() { "!" } else { " " }.to_string();
+
+        let cells = match &report.read_error {
+            Some(err) => [err.clone(), String::new()],
+            None => {
+                let cell = |index: usize| match report.parts.get(index) {
+                    Some(part_report) => format_cell(part_report),
+                    None => String::new(),
+                };
+                [cell(0), cell(1)]
+            }
+        };
+
+        let [part_one, part_two] = cells;
+        rows.push([flag, report.path.clone(), part_one, part_two]);
+    }
+
+    // Align columns
+    let mut widths = [0; 4];
+    for row in &rows {
+        for (i, cell) in row.iter().enumerate() {
+            widths[i] = widths[i].max(cell.chars().count());
+        }
+    }
+
+    let mut table = String::new();
+    for row in &rows {
+        let line = format!(
+            "{:<w0$} {:<w1$}  {:<w2$}  {}",
+            row[0],
+            row[1],
+            row[2],
+            row[3],
+            w0 = widths[0],
+            w1 = widths[1],
+            w2 = widths[2],
+        );
+        writeln!(table, "{}", line.trim_end()).unwrap();
+    }
+
+    table
+}
+
+fn format_cell(part_report: &PartReport) -> String {
+    match &part_report.check {
+        Check::Unknown => part_report.outcome.to_string(),
+        Check::Correct => format!("{} ✓", part_report.outcome),
+        Check::Incorrect { expected } => {
+            format!("{} ✗ (expected {expected})", part_report.outcome)
+        }
+    }
+}
@@ -0,0 +1,452 @@
+//! Personal statistics ledger - when each day/part was started and solved, submitted attempts and solver times.
+//! Events are appended by `aoc run` (solver runs) and `aoc submit` (attempts), `aoc stats` summarises them.
+//!
+//! File format - append-only log, one event per line, tab separated (aligned below for readability), `#` starts a comment:
+//! ```text
+//! # timestamp  year  day  part  input    event    values
+//! 1733029920   2024  1    1     2024_01  run      1532  2904518   # solver time in microseconds, answer (`-` if failed)
+//! 1733030100   2024  1    1     2024_01  attempt  2904518         # submitted answer
+//! ```
+//! Day counts as started by its first event. Part is solved by the first event with the accepted answer
+//! (see `crate::answers`) of the personal input, attempts are its distinct submitted answers.
+//! Dates are in the Advent of Code time zone (UTC-5) - puzzles unlock at midnight.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt::Write as _,
+    fs,
+    io::{self, Write as _},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    answers::{input_name, AnswerStore},
+    error::{column_of, Error, Result},
+    registry::{Outcome, Part},
+    runner::InputReport,
+};
+
+pub const STATS_PATH: &str = "./resources/stats.txt";
+
+const HEADER: &str = "# timestamp\tyear\tday\tpart\tinput\tevent\tvalues\n";
+const FAILED_ANSWER: &str = "-";
+const UTC_OFFSET_SECONDS: i64 = -5 * 3600;
+const DAY_SECONDS: i64 = 24 * 3600;
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Event {
+    /// Solver run, `None` answer if it failed or panicked.
+    Run {
+        elapsed: Duration,
+        answer: Option<String>,
+    },
+    /// Submitted answer.
+    Attempt { answer: String },
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Entry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub year: u16,
+    pub day: u8,
+    pub part: Part,
+    /// Input name, see `crate::answers::input_name()`.
+    pub input: String,
+    pub event: Event,
+}
+
+impl Entry {
+    fn answer(&self) -> Option<&str> {
+        match &self.event {
+            Event::Run { answer, .. } => answer.as_deref(),
+            Event::Attempt { answer } => Some(answer),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Ledger {
+    pub entries: Vec<Entry>,
+}
+
+impl Ledger {
+    /// Load ledger from file. Missing file is an empty ledger.
+    pub fn load(file_path: &str) -> Result<Self> {
+        match fs::read_to_string(file_path) {
+            Ok(content) => Self::parse(&content).map_err(|err| err.with_file(file_path)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(Error::new(format!("Failed to read file: {err}")).with_file(file_path)),
+        }
+    }
+
+    pub fn parse(content: &str) -> Result<Self> {
+        let mut entries = vec![];
+
+        for (i, line) in content.lines().enumerate() {
+            let line_num = i + 1;
+            let line = line.split('#').next().unwrap_or_default();
+            let fields: Vec<&str> = line.split('\t').map(str::trim).collect();
+
+            if fields.iter().all(|field| field.is_empty()) {
+                continue;
+            }
+
+            let invalid_value = |value: &str| {
+                Error::at(
+                    line_num,
+                    column_of(line, value),
+                    format!("Invalid value '{value}'."),
+                )
+            };
+
+            let (timestamp, year, day, part, input, event) = match fields[..] {
+                [timestamp, year, day, part, input, "run", elapsed, answer] => {
+                    let elapsed = elapsed.parse().map_err(|_| invalid_value(elapsed))?;
+                    let answer = match answer {
+                        FAILED_ANSWER => None,
+                        _ => Some(answer.to_string()),
+                    };
+                    let event = Event::Run {
+                        elapsed: Duration::from_micros(elapsed),
+                        answer,
+                    };
+                    (timestamp, year, day, part, input, event)
+                }
+                [timestamp, year, day, part, input, "attempt", answer] => {
+                    let event = Event::Attempt {
+                        answer: answer.to_string(),
+                    };
+                    (timestamp, year, day, part, input, event)
+                }
+                _ => {
+                    return Err(Error::at_line(
+                        line_num,
+                        "Expected `timestamp year day part input run elapsed answer` or \
+                         `timestamp year day part input attempt answer` (tab separated).",
+                    ))
+                }
+            };
+
+            entries.push(Entry {
+                timestamp: timestamp.parse().map_err(|_| invalid_value(timestamp))?,
+                year: year.parse().map_err(|_| invalid_value(year))?,
+                day: day.parse().map_err(|_| invalid_value(day))?,
+                part: match part {
+                    "1" => Part::One,
+                    "2" => Part::Two,
+                    _ => return Err(invalid_value(part)),
+                },
+                input: input.to_string(),
+                event,
+            });
+        }
+
+        Ok(Self { entries })
+    }
+
+    /// Append entries to the ledger file, created (with a header) if missing.
+    pub fn append(file_path: &str, entries: &[Entry]) -> Result<()> {
+        let write_error = |err: io::Error| {
+            Error::new(format!("Failed to write file: {err}")).with_file(file_path)
+        };
+
+        let mut file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(file_path)
+            .map_err(write_error)?;
+
+        let mut content = String::new();
+        if file.metadata().map_err(write_error)?.len() == 0 {
+            content.push_str(HEADER);
+        }
+        for entry in entries {
+            content.push_str(&format_entry(entry));
+        }
+
+        file.write_all(content.as_bytes()).map_err(write_error)
+    }
+}
+
+fn format_entry(entry: &Entry) -> String {
+    // Tabs and newlines would break the format
+    let clean = |answer: &str| answer.replace(['\t', '\n', '#'], " ");
+
+    let event = match &entry.event {
+        Event::Run { elapsed, answer } => format!(
+            "run\t{}\t{}",
+            elapsed.as_micros(),
+            answer.as_deref().map_or(FAILED_ANSWER.to_string(), clean)
+        ),
+        Event::Attempt { answer } => format!("attempt\t{}", clean(answer)),
+    };
+
+    format!(
+        "{}\t{}\t{}\t{}\t{}\t{event}\n",
+        entry.timestamp,
+        entry.year,
+        entry.day,
+        entry.part.number(),
+        entry.input,
+    )
+}
+
+/// Seconds since the Unix epoch.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs())
+}
+
+/// Run events of the reports, unreadable inputs are skipped.
+pub fn run_entries(year: u16, day: u8, reports: &[InputReport], timestamp: u64) -> Vec<Entry> {
+    reports
+        .iter()
+        .flat_map(|report| {
+            report.parts.iter().map(|part_report| Entry {
+                timestamp,
+                year,
+                day,
+                part: part_report.part,
+                input: input_name(&report.path),
+                event: Event::Run {
+                    elapsed: part_report.elapsed,
+                    answer: match &part_report.outcome {
+                        Outcome::Answer(answer) => Some(answer.clone()),
+                        _ => None,
+                    },
+                },
+            })
+        })
+        .collect()
+}
+
+#[derive(Default, Debug)]
+pub struct PartStats {
+    /// Timestamp of the first event with the accepted answer.
+    pub solved: Option<u64>,
+    /// Distinct submitted answers.
+    pub attempts: usize,
+    /// Distinct submitted answers other than the accepted one (if known).
+    pub wrong_attempts: usize,
+    /// Fastest solver run with an answer.
+    pub best_time: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub struct DayStats {
+    pub year: u16,
+    pub day: u8,
+    /// Timestamp of the first event.
+    pub started: u64,
+    pub parts: [PartStats; 2],
+}
+
+/// Statistics of each day with events of its `personal_input` (input name by year and day), ordered by year and day.
+pub fn summarize(
+    ledger: &Ledger,
+    store: &AnswerStore,
+    personal_input: impl Fn(u16, u8) -> String,
+) -> Vec<DayStats> {
+    let mut days: BTreeMap<(u16, u8), DayStats> = BTreeMap::new();
+    let mut submitted: HashSet<(u16, u8, Part, &str)> = HashSet::new();
+
+    let mut entries: Vec<&Entry> = ledger.entries.iter().collect();
+    entries.sort_by_key(|entry| entry.timestamp);
+
+    for entry in entries {
+        if entry.input != personal_input(entry.year, entry.day) {
+            continue;
+        }
+
+        let day_stats = days
+            .entry((entry.year, entry.day))
+            .or_insert_with(|| DayStats {
+                year: entry.year,
+                day: entry.day,
+                started: entry.timestamp,
+                parts: Default::default(),
+            });
+        let part_stats = &mut day_stats.parts[entry.part.number() - 1];
+        let accepted = store.get(entry.year, entry.day, &entry.input, entry.part);
+
+        if part_stats.solved.is_none() && accepted.is_some() && entry.answer() == accepted {
+            part_stats.solved = Some(entry.timestamp);
+        }
+
+        match &entry.event {
+            Event::Run {
+                elapsed,
+                answer: Some(_),
+            } => {
+                part_stats.best_time = Some(
+                    part_stats
+                        .best_time
+                        .map_or(*elapsed, |best| best.min(*elapsed)),
+                );
+            }
+            Event::Run { answer: None, .. } => (),
+            Event::Attempt { answer } => {
+                if submitted.insert((entry.year, entry.day, entry.part, answer)) {
+                    part_stats.attempts += 1;
+                    if accepted.is_some_and(|accepted| accepted != answer) {
+                        part_stats.wrong_attempts += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    days.into_values().collect()
+}
+
+/// Per-year summary line followed by a table of the year's days.
+/// Streak is the number of consecutive calendar days with a star earned - current one ends `today` or the day before.
+pub fn format_stats(days: &[DayStats], now: u64) -> String {
+    let mut output = String::new();
+    let today = calendar_day(now);
+
+    let mut years: Vec<u16> = days.iter().map(|day_stats| day_stats.year).collect();
+    years.dedup();
+
+    for year in years {
+        let year_days: Vec<&DayStats> = days
+            .iter()
+            .filter(|day_stats| day_stats.year == year)
+            .collect();
+        let parts = || year_days.iter().flat_map(|day_stats| &day_stats.parts);
+
+        let stars = parts().filter(|part| part.solved.is_some()).count();
+        let attempts: usize = parts().map(|part| part.attempts).sum();
+        let wrong_attempts: usize = parts().map(|part| part.wrong_attempts).sum();
+
+        let star_days: Vec<i64> = parts()
+            .filter_map(|part| part.solved.map(calendar_day))
+            .collect();
+        let (current_streak, longest_streak) = streaks(&star_days, today);
+
+        if !output.is_empty() {
+            output.push('\n');
+        }
+        writeln!(
+            output,
+            "{year}: {stars} stars, {} days started, {attempts} attempts ({wrong_attempts} wrong), \
+             streak {current_streak} (longest {longest_streak})",
+            year_days.len()
+        )
+        .unwrap();
+
+        // Solve times are counted from the start of the day
+        writeln!(
+            output,
+            "Day  Started           Part One  Part Two  Attempts  Solver"
+        )
+        .unwrap();
+        for day_stats in year_days {
+            let solve_time = |part: &PartStats| {
+                part.solved.map_or("-".to_string(), |solved| {
+                    format_duration(solved.saturating_sub(day_stats.started))
+                })
+            };
+            let solver_time = |part: &PartStats| {
+                part.best_time
+                    .map_or("-".to_string(), |time| format!("{time:.2?}"))
+            };
+            let [part_one, part_two] = &day_stats.parts;
+
+            writeln!(
+                output,
+                "{:>3}  {}  {:>8}  {:>8}  {:>8}  {} / {}",
+                day_stats.day,
+                format_timestamp(day_stats.started),
+                solve_time(part_one),
+                solve_time(part_two),
+                part_one.attempts + part_two.attempts,
+                solver_time(part_one),
+                solver_time(part_two),
+            )
+            .unwrap();
+        }
+    }
+
+    if output.is_empty() {
+        output.push_str("No statistics recorded yet.\n");
+    }
+
+    output
+}
+
+/// Current (ending `today` or the day before) and longest run of consecutive calendar days.
+fn streaks(days: &[i64], today: i64) -> (usize, usize) {
+    let mut days = days.to_vec();
+    days.sort_unstable();
+    days.dedup();
+
+    let mut longest = 0;
+    let mut length = 0;
+    let mut previous = None;
+    for &day in &days {
+        length = match previous {
+            Some(previous) if day == previous + 1 => length + 1,
+            _ => 1,
+        };
+        longest = longest.max(length);
+        previous = Some(day);
+    }
+
+    let current = match previous {
+        Some(last) if last >= today - 1 => length,
+        _ => 0,
+    };
+
+    (current, longest)
+}
+
+/// Days since the Unix epoch in the Advent of Code time zone.
+fn calendar_day(timestamp: u64) -> i64 {
+    (timestamp as i64 + UTC_OFFSET_SECONDS).div_euclid(DAY_SECONDS)
+}
+
+/// `YYYY-MM-DD HH:MM` in the Advent of Code time zone.
+fn format_timestamp(timestamp: u64) -> String {
+    let seconds = timestamp as i64 + UTC_OFFSET_SECONDS;
+    let (year, month, day) = civil_from_days(seconds.div_euclid(DAY_SECONDS));
+    let time = seconds.rem_euclid(DAY_SECONDS);
+
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        time / 3600,
+        time % 3600 / 60
+    )
+}
+
+/// `H:MM:SS`, hours are not wrapped to days.
+pub(crate) fn format_duration(seconds: u64) -> String {
+    format!(
+        "{}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+/// Gregorian date of days since the Unix epoch - http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
@@ -0,0 +1,127 @@
+//! Text extraction helpers - numbers embedded in a line (`Button A: X+83, Y+59`, `p=63,14 v=-55,-32`) and
+//! blank-line-separated blocks.
+//!
+//! Errors of the line functions point to line 1 (the line itself), callers set the real line with
+//! `Error::with_line`.
+
+use std::{any::type_name, str::FromStr};
+
+use crate::error::{column_of, Error, Result};
+
+/// Integer types numbers can be extracted as.
+pub trait Int: FromStr {
+    /// Whether a `-` right before the digits belongs to the number - for unsigned types it's just a separator.
+    const SIGNED: bool;
+}
+
+macro_rules! impl_int {
+    ($signed:expr => $($t:ty),*) => {
+        $(
+            impl Int for $t {
+                const SIGNED: bool = $signed;
+            }
+        )*
+    };
+}
+
+impl_int!(true => i8, i16, i32, i64, i128, isize);
+impl_int!(false => u8, u16, u32, u64, u128, usize);
+
+/// Exactly `N` numbers of the line, anything else than digits (and the minus sign for signed types) separates them.
+pub fn ints<T: Int, const N: usize>(line: &str) -> Result<[T; N]> {
+    Ok(ints_with_columns::<T, N>(line)?.map(|(value, _)| value))
+}
+
+/// All numbers of the line, see `ints`.
+pub fn ints_vec<T: Int>(line: &str) -> Result<Vec<T>> {
+    tokens::<T>(line)
+        .map(|token| parse_token(line, token))
+        .collect()
+}
+
+/// Exactly `N` numbers of the line with their 1-based columns - for errors pointing to a particular number.
+pub fn ints_with_columns<T: Int, const N: usize>(line: &str) -> Result<[(T, usize); N]> {
+    let count = tokens::<T>(line).count();
+    if count != N {
+        return Err(Error::at_line(
+            1,
+            format!("Expected {N} numbers, found {count}."),
+        ));
+    }
+
+    let values = tokens::<T>(line)
+        .map(|token| Ok((parse_token(line, token)?, column_of(line, token))))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(values
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("Count is checked.")))
+}
+
+fn parse_token<T: Int>(line: &str, token: &str) -> Result<T> {
+    token.parse().map_err(|_| {
+        Error::at(
+            1,
+            column_of(line, token),
+            format!("Number '{token}' doesn't fit {}.", type_name::<T>()),
+        )
+    })
+}
+
+/// Digit runs of the line, with the preceding minus sign for signed types.
+fn tokens<T: Int>(line: &str) -> impl Iterator<Item = &str> {
+    let bytes = line.as_bytes();
+    let mut index = 0;
+
+    std::iter::from_fn(move || {
+        while index < bytes.len() && !bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if index == bytes.len() {
+            return None;
+        }
+
+        let mut start = index;
+        if T::SIGNED && start > 0 && bytes[start - 1] == b'-' {
+            start -= 1;
+        }
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+
+        Some(&line[start..index])
+    })
+}
+
+/// Groups of lines separated by blank lines, see `Blocks`.
+pub fn blocks(content: &str) -> Blocks<'_> {
+    Blocks {
+        lines: content.lines().enumerate().peekable(),
+    }
+}
+
+/// Iterator over blank-line-separated blocks - the 1-based line number of the block's first line and the block's
+/// lines (without the trailing newline). Any number of blank (or whitespace-only) lines separates blocks.
+pub struct Blocks<'a> {
+    lines: std::iter::Peekable<std::iter::Enumerate<std::str::Lines<'a>>>,
+}
+
+impl<'a> Iterator for Blocks<'a> {
+    type Item = (usize, Vec<&'a str>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self
+            .lines
+            .next_if(|(_, line)| line.trim().is_empty())
+            .is_some()
+        {}
+
+        let (first_index, first_line) = self.lines.next()?;
+        let mut block = vec![first_line];
+        while let Some((_, line)) = self.lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            block.push(line);
+        }
+
+        Some((first_index + 1, block))
+    }
+}
@@ -0,0 +1,49 @@
+//! Tracing of solver internals (parsing, iterations, recursion summaries) to stderr, answers stay on stdout.
+//! Verbosity is controlled by `-v` (debug - spans and summaries) and `-vv` (trace - every iteration).
+//! Without `-v` no subscriber is installed, disabled events are skipped without evaluating their fields.
+
+use std::io::{self, IsTerminal};
+
+use tracing::Level;
+
+/// Install the stderr subscriber for the verbosity level (0 => no output).
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => return,
+        1 => Level::DEBUG,
+        _ => Level::TRACE,
+    };
+
+    // Already installed (e.g. called twice) => keep the first one
+    let _ = tracing_subscriber::fmt()
+        .with_max_level(level)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_target(false)
+        .try_init();
+}
+
+/// Verbosity flag (`-v`, `-vv`, `-vvv`, ...), `None` if the argument is not a verbosity flag.
+pub fn parse_verbosity(arg: &str) -> Option<u8> {
+    let count = arg
+        .strip_prefix('-')?
+        .chars()
+        .take_while(|c| *c == 'v')
+        .count();
+
+    match count > 0 && count == arg.len() - 1 {
+        true => Some(count.min(u8::MAX as usize) as u8),
+        false => None,
+    }
+}
+
+/// Install the subscriber according to the process arguments - used by the per-day binaries.
+pub fn init_from_args() {
+    let verbosity = std::env::args()
+        .skip(1)
+        .filter_map(|arg| parse_verbosity(&arg))
+        .max()
+        .unwrap_or(0);
+
+    init(verbosity);
+}
@@ -0,0 +1,159 @@
+//! Watch mode - re-run a solution whenever its input or example files change.
+//! Files are polled for modification time, the solution itself runs in-process via the registry.
+//! When the day's source file (`src/y<year>/day<NN>.rs`, relative to the working directory) changes, the binary is
+//! rebuilt with cargo and the watch restarts in the fresh build via `cargo run`. Changes of shared modules are not
+//! detected, a failed build keeps watching with the previous one.
+
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::{
+    collections::HashMap,
+    env, fs,
+    process::Command,
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use crate::{
+    config::Config,
+    input::read_input,
+    params::{sidecar_path, Params},
+    registry::{Outcome, Solution, PARTS},
+};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Watch input and example files (and their parameter sidecar files) of the solution's day forever.
+pub fn watch(solution: &Solution, config: &Config, overrides: &Params) -> ! {
+    let defaults = config.params(solution.year, solution.day);
+    let mut modified_times: HashMap<String, [Option<SystemTime>; 2]> = HashMap::new();
+    let mut previous_outcomes: HashMap<String, Vec<Outcome>> = HashMap::new();
+    let source_path = format!("src/y{}/day{:02}.rs", solution.year, solution.day);
+    let mut source_modified_time = modified_time(&source_path);
+
+    println!(
+        "Watching inputs of {}/{:02}, press Ctrl+C to stop.",
+        solution.year, solution.day
+    );
+
+    loop {
+        if modified_time(&source_path) != source_modified_time {
+            source_modified_time = modified_time(&source_path);
+            println!("\n{source_path} changed, rebuilding...");
+            restart();
+        }
+
+        // Examples first, so the real input (usually the slowest) is solved last.
+        let mut paths = config.example_paths(solution.year, solution.day);
+        paths.push(config.input_path(solution.year, solution.day));
+
+        for path in paths {
+            let modified_times_now = [modified_time(&path), modified_time(&sidecar_path(&path))];
+
+            if modified_times.get(&path) == Some(&modified_times_now) {
+                continue;
+            }
+            modified_times.insert(path.clone(), modified_times_now);
+
+            if modified_times_now[0].is_none() {
+                println!("\n{path}: not found");
+                continue;
+            }
+
+            let outcomes = run_file(
+                solution,
+                &path,
+                &defaults,
+                overrides,
+                previous_outcomes.get(&path),
+            );
+            previous_outcomes.insert(path, outcomes);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn modified_time(path: &str) -> Option<SystemTime> {
+    fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Rebuild the binary and replace this process with the fresh build, run with the same arguments.
+/// Returns (and keeps the current build running) only if the build or the restart failed.
+fn restart() {
+    // Same profile as the running binary
+    let cargo = |command: &str| {
+        let mut cargo = Command::new("cargo");
+        cargo.args([command, "--quiet", "--bin", "aoc"]);
+        if !cfg!(debug_assertions) {
+            cargo.arg("--release");
+        }
+        cargo
+    };
+
+    match cargo("build").status() {
+        Ok(status) if status.success() => {}
+        Ok(_) => {
+            println!("  Build failed, watching with the previous build.");
+            return;
+        }
+        Err(err) => {
+            println!("  Failed to run cargo: {err}");
+            return;
+        }
+    }
+
+    let mut run = cargo("run");
+    run.arg("--").args(env::args().skip(1));
+
+    #[cfg(unix)]
+    let err = run.exec();
+    #[cfg(not(unix))]
+    let err = match run.status() {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(err) => err,
+    };
+    println!("  Failed to restart: {err}");
+}
+
+/// Solve both parts for the file and print the answers compared to the previous run.
+fn run_file(
+    solution: &Solution,
+    path: &str,
+    defaults: &Params,
+    overrides: &Params,
+    previous: Option<&Vec<Outcome>>,
+) -> Vec<Outcome> {
+    println!("\n{path}:");
+
+    let loaded = read_input(path)
+        .and_then(|input| Ok((input, solution.params_for_input(path, defaults, overrides)?)));
+    let (input, params) = match loaded {
+        Ok(val) => val,
+        Err(err) => {
+            println!("  {err}");
+            return vec![];
+        }
+    };
+
+    let mut outcomes = vec![];
+
+    for (i, part) in PARTS.into_iter().enumerate() {
+        let start = Instant::now();
+        let outcome = solution.solve(part, &input, &params);
+        let elapsed = start.elapsed();
+
+        let change = match previous.and_then(|outcomes| outcomes.get(i)) {
+            None => String::new(),
+            Some(previous_outcome) if *previous_outcome == outcome => " (unchanged)".to_string(),
+            Some(previous_outcome) => format!(" (was {previous_outcome})"),
+        };
+
+        println!("  {part}: {outcome}{change} [{elapsed:.2?}]");
+        outcomes.push(outcome);
+    }
+
+    outcomes
+}
@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use crate::{
+    error::{Error, Result},
+    parse_columns,
+};
+
+/// Solution for https://adventofcode.com/2024/day/1 - Part One.
+pub fn total_distance(input: &str) -> Result<i32> {
+    // Parse input
+    let mut columns: [Vec<i32>; 2] = parse_columns(input)?;
+
+    // Sort columns
+    for column in columns.iter_mut() {
+        column.sort();
+    }
+
+    // Calculate total distance
+    calculate_distance_sum(columns)
+}
+
+/// Solution for https://adventofcode.com/2024/day/1 - Part Two.
+pub fn similarity_score(input: &str) -> Result<i32> {
+    // Parse input
+    let [left_column, right_column]: [Vec<i32>; 2] = parse_columns(input)?;
+
+    // Create a map from right_column [item value => occurrence count]
+    let mut right_count_map: HashMap<i32, usize> = HashMap::new();
+    for item in right_column {
+        match right_count_map.get_mut(&item) {
+            Some(count) => {
+                *count += 1;
+            }
+            None => {
+                right_count_map.insert(item, 1);
+            }
+        };
+    }
+
+    // Calculate similarity score
+    let mut similarity_score: i32 = 0;
+    for (i, item) in left_column.into_iter().enumerate() {
+        if let Some(count) = right_count_map.get(&item) {
+            let line_num = i + 1;
+            let overflow = || Error::at_line(line_num, "Similarity score overflowed i32.");
+            let i32_count = i32::try_from(*count).map_err(|_| overflow())?;
+            similarity_score = item
+                .checked_mul(i32_count)
+                .and_then(|score| similarity_score.checked_add(score))
+                .ok_or_else(overflow)?;
+        }
+    }
+
+    Ok(similarity_score)
+}
+
+fn calculate_distance_sum(sorted_columns: [Vec<i32>; 2]) -> Result<i32> {
+    let [column1, column2] = sorted_columns;
+    let mut distance_sum: i32 = 0;
+
+    for (i, value1) in column1.into_iter().enumerate() {
+        let value2 = column2[i]; // no need to check whether exists - already validated while parsing file
+                                 // Pairs are sorted, so there is no input line to point to
+        distance_sum = i32::try_from(value1.abs_diff(value2))
+            .ok()
+            .and_then(|distance| distance_sum.checked_add(distance))
+            .ok_or_else(|| Error::new("Total distance overflowed i32."))?;
+    }
+
+    Ok(distance_sum)
+}
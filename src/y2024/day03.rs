@@ -0,0 +1,103 @@
+use regex::Regex;
+
+use crate::error::{Error, Result};
+
+/// Solution for https://adventofcode.com/2024/day/3 - Part One.
+pub fn multiplication_sum(input: &str) -> Result<i32> {
+    // Prepare regex
+    let expression_regex = Regex::new(r"mul\([0-9]+,[0-9]+\)").unwrap();
+
+    // Find and evaluate expressions
+    let mut sum = 0;
+    let expressions = expression_regex.find_iter(input);
+
+    for expression in expressions {
+        let product = evaluate_multiplication(expression.as_str())
+            .map_err(|err| positioned(err, input, expression.start()))?;
+        sum = add_product(sum, product, input, expression.start())?;
+    }
+
+    Ok(sum)
+}
+
+/// Solution for https://adventofcode.com/2024/day/3 - Part Two.
+pub fn enabled_multiplication_sum(input: &str) -> Result<i32> {
+    // Prepare regex
+    let expression_regex = Regex::new(r"(do\(\))|don't\(\)|mul\([0-9]+,[0-9]+\)").unwrap(); // find `do()` `don't()` or a valid `mul(a,b)`
+
+    // Find and evaluate expressions
+    let mut sum = 0;
+    let mut enabled = true;
+    let expressions = expression_regex.find_iter(input);
+
+    for expression_match in expressions {
+        let expression = expression_match.as_str();
+
+        if expression == "do()" {
+            enabled = true;
+            continue;
+        } else if expression == "don't()" {
+            enabled = false;
+            continue;
+        }
+
+        if !enabled {
+            continue;
+        }
+
+        let product = evaluate_multiplication(expression)
+            .map_err(|err| positioned(err, input, expression_match.start()))?;
+        sum = add_product(sum, product, input, expression_match.start())?;
+    }
+
+    Ok(sum)
+}
+
+/// Entry point for `crate::fuzz` - the expression search (there is no separate parser) must never panic.
+pub(crate) fn fuzz_expressions(content: &str) {
+    let _ = multiplication_sum(content);
+    let _ = enabled_multiplication_sum(content);
+}
+
+/// Add the product of the expression starting at byte `offset` to the running sum.
+fn add_product(sum: i32, product: i32, input: &str, offset: usize) -> Result<i32> {
+    sum.checked_add(product)
+        .ok_or_else(|| positioned(Error::new("Sum of products overflowed i32."), input, offset))
+}
+
+/// Point `err` to the line and column of byte `offset` in `input`.
+fn positioned(err: Error, input: &str, offset: usize) -> Error {
+    let before = &input[..offset];
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    Error::at(
+        before.matches('\n').count() + 1,
+        before[line_start..].chars().count() + 1,
+        err.description,
+    )
+}
+
+/// Evaluate an already validated `mul(a,b)` expression.
+fn evaluate_multiplication(expression: &str) -> Result<i32> {
+    // No need to validate the shape, already validated by regex.
+    let (first, second) = expression
+        .trim_start_matches("mul(")
+        .trim_end_matches(')')
+        .split_once(',')
+        .expect("Expression is validated by regex.");
+
+    // Parse first and second number
+    let mut numbers = vec![];
+    for number_string in [first, second] {
+        let number = number_string.parse::<i32>().map_err(|_| {
+            Error::new(format!(
+                "Number '{number_string}' in '{expression}' overflowed i32."
+            ))
+        })?; // Is number - validated already by regex, but can over/underflow.
+        numbers.push(number);
+    }
+
+    // a*b
+    numbers[0]
+        .checked_mul(numbers[1])
+        .ok_or_else(|| Error::new(format!("Result of '{expression}' overflowed i32.")))
+}
@@ -0,0 +1,144 @@
+use crate::error::Result;
+
+/// Solution for https://adventofcode.com/2024/day/4 - Part One.
+pub fn xmas_count(input: &str) -> Result<usize> {
+    let grid = parse_grid(input);
+
+    // Prepare directions
+    let directions = [
+        (AxisDirection::Negative, AxisDirection::Positive), // up left
+        (AxisDirection::None, AxisDirection::Positive),     // up
+        (AxisDirection::Positive, AxisDirection::Positive), // up right
+        (AxisDirection::Negative, AxisDirection::None),     // left
+        (AxisDirection::Positive, AxisDirection::None),     // right
+        (AxisDirection::Negative, AxisDirection::Negative), // down left
+        (AxisDirection::None, AxisDirection::Negative),     // down
+        (AxisDirection::Positive, AxisDirection::Negative), // down right
+    ];
+
+    // Find each 'X' and try each direction from it.
+    let mut total_count = 0;
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, char) in row.iter().enumerate() {
+            if *char != 'X' {
+                continue;
+            }
+
+            for direction in &directions {
+                if test_direction(&grid, x, y, direction) {
+                    total_count += 1;
+                };
+            }
+        }
+    }
+
+    Ok(total_count)
+}
+
+/// Solution for https://adventofcode.com/2024/day/4 - Part Two.
+pub fn x_mas_count(input: &str) -> Result<usize> {
+    let grid = parse_grid(input);
+
+    // Find each 'A' and test it.
+    let mut total_count = 0;
+
+    for (y, row) in grid.iter().enumerate() {
+        for (x, char) in row.iter().enumerate() {
+            if *char != 'A' {
+                continue;
+            }
+
+            if test_cross(&grid, x, y) {
+                total_count += 1;
+            }
+        }
+    }
+
+    Ok(total_count)
+}
+
+/// Entry point for `crate::fuzz` - the search must never panic, rows don't have to be equally long.
+pub(crate) fn fuzz_grid_search(content: &str) {
+    let _ = xmas_count(content);
+    let _ = x_mas_count(content);
+}
+
+/// Parse lines into grid
+fn parse_grid(content: &str) -> Vec<Vec<char>> {
+    let mut grid: Vec<Vec<char>> = Vec::new();
+
+    for line in content.lines() {
+        grid.push(line.chars().collect());
+    }
+
+    grid
+}
+
+fn test_direction(
+    grid: &[Vec<char>],
+    x: usize,
+    y: usize,
+    direction: &(AxisDirection, AxisDirection),
+) -> bool {
+    for (i, character) in ['M', 'A', 'S'].into_iter().enumerate() {
+        // overflows not handled for simplicity
+        let distance = i + 1;
+
+        let tested_x = match direction.0 {
+            AxisDirection::Positive => x + distance,
+            AxisDirection::Negative => match x.checked_sub(distance) {
+                Some(val) => val,
+                None => return false, // x out of bounds (less than 0)
+            },
+            AxisDirection::None => x,
+        };
+        let tested_y = match direction.1 {
+            AxisDirection::Positive => y + distance,
+            AxisDirection::Negative => match y.checked_sub(distance) {
+                Some(val) => val,
+                None => return false, // y out of bounds (less than 0)
+            },
+            AxisDirection::None => y,
+        };
+
+        let out_of_bounds = tested_y >= grid.len() || tested_x >= grid[tested_y].len(); // less than 0 already checked
+
+        if out_of_bounds {
+            return false;
+        }
+
+        if grid[tested_y][tested_x] != character {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn test_cross(grid: &[Vec<char>], x: usize, y: usize) -> bool {
+    let diagonal = |dx: isize, dy: isize| {
+        let row = grid.get(y.checked_add_signed(dy)?)?;
+        row.get(x.checked_add_signed(dx)?).copied()
+    };
+
+    // Off the grid - on the edge or next to a shorter row
+    let (Some(left_up), Some(right_up), Some(left_down), Some(right_down)) = (
+        diagonal(-1, -1),
+        diagonal(1, -1),
+        diagonal(-1, 1),
+        diagonal(1, 1),
+    ) else {
+        return false;
+    };
+
+    let is_mas = |pair| matches!(pair, ('M', 'S') | ('S', 'M'));
+
+    is_mas((left_up, right_down)) && is_mas((right_up, left_down))
+}
+
+enum AxisDirection {
+    Positive,
+    Negative,
+    None,
+}
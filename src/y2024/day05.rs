@@ -0,0 +1,163 @@
+use crate::{
+    error::{column_of, Error, Result},
+    graph::DiGraph,
+};
+
+/// Solution for https://adventofcode.com/2024/day/5 - Part One.
+/// Sum of middle values of the originally valid updates.
+pub fn valid_middle_sum(input: &str) -> Result<u32> {
+    let (rules, updates) = parse_file(input)?;
+
+    let rule_graph = create_rule_graph(rules);
+
+    let mut middle_sum_valid: u32 = 0;
+
+    for update in updates {
+        if is_valid_update(&update.values, &rule_graph) {
+            middle_sum_valid += update.values[update.values.len() / 2] as u32;
+        }
+    }
+
+    Ok(middle_sum_valid)
+}
+
+/// Solution for https://adventofcode.com/2024/day/5 - Part Two.
+/// Sum of middle values of the sorted (originally invalid) updates.
+pub fn sorted_middle_sum(input: &str) -> Result<u32> {
+    let (rules, updates) = parse_file(input)?;
+
+    let rule_graph = create_rule_graph(rules);
+
+    let mut middle_sum_invalid: u32 = 0;
+
+    for update in updates {
+        if !is_valid_update(&update.values, &rule_graph) {
+            let sorted_update =
+                sort_invalid_update(&update.values, &rule_graph).map_err(|cycle| {
+                    let cycle: Vec<String> = cycle.iter().map(u8::to_string).collect();
+                    Error::at_line(
+                        update.line_num,
+                        format!(
+                            "Update can't be sorted, rules are cyclic: {} -> {}.",
+                            cycle.join(" -> "),
+                            cycle[0]
+                        ),
+                    )
+                })?;
+            middle_sum_invalid += sorted_update[sorted_update.len() / 2] as u32;
+        }
+    }
+
+    Ok(middle_sum_invalid)
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<(Vec<[u8; 2]>, Vec<Update>)> {
+    let mut rules = vec![];
+    let mut updates = vec![];
+
+    let mut is_first_part = true;
+
+    for (i, line) in content.lines().enumerate() {
+        if line.is_empty() {
+            is_first_part = false;
+            continue;
+        }
+
+        let line_num = i + 1;
+
+        let parse_line = |separator: &str| {
+            line.split(separator)
+                .map(|raw_value| {
+                    raw_value.parse::<u8>().map_err(|_| {
+                        Error::at(
+                            line_num,
+                            column_of(line, raw_value),
+                            format!("Failed to parse value '{raw_value}'."),
+                        )
+                    })
+                })
+                .collect::<Result<Vec<u8>>>()
+        };
+
+        if is_first_part {
+            let rule = parse_line("|")?;
+            let validated_rule: [u8; 2] = rule
+                .try_into()
+                .map_err(|_| Error::at_line(line_num, "Exactly two values expected."))?;
+            rules.push(validated_rule);
+        } else {
+            let values = parse_line(",")?;
+            if values.len().is_multiple_of(2) {
+                return Err(Error::at_line(
+                    line_num,
+                    "Update must be odd otherwise there is no middle.",
+                ));
+            }
+            // A repeated page would be merged when sorting and shift the middle
+            if let Some(j) = (1..values.len()).find(|&j| values[..j].contains(&values[j])) {
+                let column = line
+                    .split(',')
+                    .take(j)
+                    .map(|raw_value| raw_value.chars().count() + 1)
+                    .sum::<usize>()
+                    + 1;
+                return Err(Error::at(
+                    line_num,
+                    column,
+                    format!("Page {} is repeated in the update.", values[j]),
+                ));
+            }
+            updates.push(Update { line_num, values });
+        }
+    }
+
+    tracing::debug!(rules = rules.len(), updates = updates.len(), "parsed");
+
+    Ok((rules, updates))
+}
+
+/// Edge `smaller -> greater` for each rule.
+fn create_rule_graph(rules: Vec<[u8; 2]>) -> DiGraph<u8> {
+    rules
+        .into_iter()
+        .map(|[smaller, greater]| (smaller, greater))
+        .collect()
+}
+
+fn is_valid_update(update: &[u8], rule_graph: &DiGraph<u8>) -> bool {
+    // Test each value whether the values to its right don't violate the rules
+    for (i, current_value) in update.iter().enumerate() {
+        for tested_value in &update[i + 1..] {
+            if rule_graph.has_edge(*tested_value, *current_value) {
+                return false;
+            }
+        }
+    }
+
+    true
+}
+
+/// Topological order of the rules between the update's values, the rule cycle if there is none.
+fn sort_invalid_update(
+    update: &[u8],
+    rule_graph: &DiGraph<u8>,
+) -> std::result::Result<Vec<u8>, Vec<u8>> {
+    let update_graph = rule_graph.induced_subgraph(update.iter().copied());
+
+    update_graph.topological_sort().ok_or_else(|| {
+        update_graph
+            .find_cycle()
+            .expect("Graph without topological order is cyclic.")
+    })
+}
+
+struct Update {
+    line_num: usize,
+    values: Vec<u8>,
+}
@@ -0,0 +1,359 @@
+use std::collections::HashSet;
+
+use crate::{
+    animation::{Recording, Snapshot},
+    cycle::find_cycle_brent,
+    error::{Error, Result},
+    Direction,
+};
+
+/// Solution for https://adventofcode.com/2024/day/6 - Part One.
+/// Could be potentially optimized if each row/column had "cached" obstacle positions
+/// and visiting grid points would be calculated not by iterating over each point individually => O(n)
+/// but by creating "move tuples" - e.g. rows[4] has obstacles [7, 8] and visited points [(2, 6), (16, 9)] => O(1).
+pub fn visited_count(input: &str) -> Result<usize> {
+    // Walk guard without adding obstacles
+    let (grid, _) = walk_guard_from_start(input, false)?;
+
+    // Count visited
+    let mut visited = 0;
+    for row in grid.rows {
+        for point in row {
+            if let GridPoint::Visited = point {
+                visited += 1;
+            }
+        }
+    }
+
+    Ok(visited)
+}
+
+/// Solution for https://adventofcode.com/2024/day/6 - Part Two.
+pub fn cycle_count(input: &str) -> Result<usize> {
+    // Walk guard + cycles (adding obstacles)
+    let (_, cycle_count) = walk_guard_from_start(input, true)?;
+
+    Ok(cycle_count)
+}
+
+/// Guard walk without added obstacles, see `crate::animation`. Iteration is the step count.
+pub fn record_walk(input: &str, recording: &Recording) -> Result<Vec<Snapshot>> {
+    let mut grid = parse_file(input)?;
+    let (x, y) = grid
+        .find_guard_coords()
+        .ok_or_else(|| Error::new("No guard found."))?;
+    let mut guard = Guard {
+        direction: Direction::Up,
+        x,
+        y,
+    };
+
+    let mut snapshots = vec![];
+    let mut states = HashSet::new();
+    let mut visited = HashSet::new();
+    let mut turns = 0;
+    let mut step = 0;
+
+    let snapshot = |grid: &Grid, guard: &Guard, step, visited: &HashSet<_>, turns| {
+        snapshot(grid, guard, step)
+            .with_metric("visited", visited.len())
+            .with_metric("turns", turns)
+    };
+
+    loop {
+        grid.set_point(GridPoint::Visited, guard.coords());
+        visited.insert(guard.coords());
+
+        if recording.is_recorded(step) {
+            snapshots.push(snapshot(&grid, &guard, step, &visited, turns));
+            if snapshots.len() == recording.frames {
+                return Ok(snapshots);
+            }
+        }
+
+        // Stop when leaving the grid or walking in a cycle
+        if !states.insert((guard.coords(), guard.direction)) {
+            break;
+        }
+        match guard.next_coords().and_then(|coords| grid.at(coords)) {
+            None => break,
+            Some(GridPoint::Obstacle) => {
+                guard.turn();
+                turns += 1;
+            }
+            Some(_) => {
+                guard.walk();
+            }
+        }
+        step += 1;
+    }
+
+    // Always show the end of the walk
+    if snapshots.last().is_none_or(|last| last.iteration != step) {
+        snapshots.push(snapshot(&grid, &guard, step, &visited, turns));
+    }
+
+    Ok(snapshots)
+}
+
+fn snapshot(grid: &Grid, guard: &Guard, step: usize) -> Snapshot {
+    let mut rows: Vec<Vec<char>> = grid
+        .rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|point| match point {
+                    GridPoint::Empty => '.',
+                    GridPoint::Obstacle => '#',
+                    GridPoint::Guard | GridPoint::Visited => 'X',
+                })
+                .collect()
+        })
+        .collect();
+
+    rows[guard.y][guard.x] = char::from(guard.direction);
+
+    Snapshot::new(step, crate::Grid::new(rows))
+}
+
+/// Walk the guard (without added obstacles) and mark the visited points.
+/// With `count_cycles` also count the obstacle positions making the walk cyclic - returned next to the grid.
+fn walk_guard_from_start(input: &str, count_cycles: bool) -> Result<(Grid, usize)> {
+    // Parse input
+    let mut grid = parse_file(input)?;
+
+    // Find guard
+    let guard_coords = grid
+        .find_guard_coords()
+        .ok_or_else(|| Error::new("No guard found."))?;
+    let mut guard = Guard {
+        direction: Direction::Up,
+        x: guard_coords.0,
+        y: guard_coords.1,
+    };
+
+    if find_cycle_brent(guard.clone(), |guard| next_guard(&grid, None, guard)).is_some() {
+        return Err(Error::new("First path is already cyclic."));
+    }
+
+    let mut loop_detector = LoopDetector::new(&grid);
+    let mut cycle_count = 0;
+    loop {
+        grid.set_point(GridPoint::Visited, guard.coords());
+
+        let Some(next) = next_guard(&grid, None, &guard) else {
+            break;
+        };
+
+        // Obstacle only where the guard hasn't been yet - it would have changed the path before
+        if count_cycles && matches!(grid.at(next.coords()), Some(GridPoint::Empty)) {
+            let obstacle = next.coords();
+            let is_cyclic = loop_detector.is_cyclic(&grid, obstacle, guard.clone());
+            tracing::trace!(?obstacle, is_cyclic, "walk finished");
+
+            if is_cyclic {
+                cycle_count += 1;
+            }
+        }
+
+        guard = next;
+    }
+    tracing::debug!(cycles = cycle_count, "guard walk finished");
+
+    Ok((grid, cycle_count))
+}
+
+/// Guard after one step (turn or move), `obstacle` is added to the grid. `None` if the guard leaves the grid.
+fn next_guard(grid: &Grid, obstacle: Option<(usize, usize)>, guard: &Guard) -> Option<Guard> {
+    let next_coords = guard.next_coords()?;
+    let next_point = grid.at(next_coords)?;
+
+    let mut guard = guard.clone();
+    match next_point {
+        GridPoint::Obstacle => guard.turn(),
+        _ if Some(next_coords) == obstacle => guard.turn(),
+        _ => {
+            guard.walk();
+        }
+    }
+
+    Some(guard)
+}
+
+/// Directions the guard left each point in - a 4-bit mask (`1 << Direction::index`) per point in a flat array.
+/// Reused across the candidate obstacles, only the points visited by the last walk are cleared.
+struct LoopDetector {
+    width: usize,
+    masks: Vec<u8>,
+    visited: Vec<usize>,
+}
+
+impl LoopDetector {
+    fn new(grid: &Grid) -> Self {
+        let width = grid.rows.iter().map(Vec::len).max().unwrap_or(0);
+
+        Self {
+            width,
+            masks: vec![0; width * grid.rows.len()],
+            visited: vec![],
+        }
+    }
+
+    /// Whether the guard walks in a loop with `obstacle` added - some point is left in the same direction twice.
+    fn is_cyclic(&mut self, grid: &Grid, obstacle: (usize, usize), mut guard: Guard) -> bool {
+        for index in self.visited.drain(..) {
+            self.masks[index] = 0;
+        }
+
+        loop {
+            let index = guard.y * self.width + guard.x;
+            let direction_bit = 1 << guard.direction.index();
+            if self.masks[index] & direction_bit != 0 {
+                return true;
+            }
+            if self.masks[index] == 0 {
+                self.visited.push(index);
+            }
+            self.masks[index] |= direction_bit;
+
+            match next_guard(grid, Some(obstacle), &guard) {
+                Some(next) => guard = next,
+                None => return false,
+            }
+        }
+    }
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<Grid> {
+    let mut rows = vec![];
+    let mut has_guard = false;
+
+    for (y, line) in content.lines().enumerate() {
+        let mut row = vec![];
+
+        for (x, character) in line.chars().enumerate() {
+            let grid_point = match character {
+                '.' => GridPoint::Empty,
+                '#' => GridPoint::Obstacle,
+                '^' => {
+                    if has_guard {
+                        return Err(Error::at(y + 1, x + 1, "Only one guard is supported."));
+                    }
+                    has_guard = true;
+                    GridPoint::Guard
+                }
+                _ => {
+                    return Err(Error::at(
+                        y + 1,
+                        x + 1,
+                        format!("Invalid character '{character}'."),
+                    ))
+                }
+            };
+            row.push(grid_point);
+        }
+
+        rows.push(row);
+    }
+
+    Ok(Grid { rows })
+}
+
+#[derive(Clone)]
+enum GridPoint {
+    Empty,
+    Obstacle,
+    Guard,
+    Visited,
+}
+
+#[derive(Clone)]
+struct Grid {
+    rows: Vec<Vec<GridPoint>>,
+}
+
+impl Grid {
+    fn at(&self, (x, y): (usize, usize)) -> Option<&GridPoint> {
+        if y >= self.rows.len() || x >= self.rows[y].len() {
+            return None;
+        };
+
+        Some(&self.rows[y][x])
+    }
+
+    fn set_point(&mut self, value: GridPoint, (x, y): (usize, usize)) {
+        if y >= self.rows.len() || x >= self.rows[y].len() {
+            panic!("Tried to set an out of bounds point.");
+        };
+
+        self.rows[y][x] = value;
+    }
+
+    fn find_guard_coords(&self) -> Option<(usize, usize)> {
+        for (y, row) in self.rows.iter().enumerate() {
+            for (x, point) in row.iter().enumerate() {
+                if let GridPoint::Guard = point {
+                    return Some((x, y));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Guard {
+    x: usize,
+    y: usize,
+    direction: Direction,
+}
+
+impl Guard {
+    fn coords(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    fn turn(&mut self) {
+        self.direction = self.direction.turn_right();
+    }
+
+    fn walk(&mut self) -> (usize, usize) {
+        let (x, y) = self
+            .next_coords()
+            .expect("Tried to move to a negative index.");
+        self.x = x;
+        self.y = y;
+
+        (x, y)
+    }
+
+    fn next_coords(&self) -> Option<(usize, usize)> {
+        let new_coords = match self.direction {
+            Direction::Up => {
+                if self.y == 0 {
+                    return None;
+                } else {
+                    (self.x, self.y - 1)
+                }
+            }
+            Direction::Left => {
+                if self.x == 0 {
+                    return None;
+                } else {
+                    (self.x - 1, self.y)
+                }
+            }
+            Direction::Right => (self.x + 1, self.y),
+            Direction::Down => (self.x, self.y + 1),
+        };
+
+        Some(new_coords)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{
+    digits::Digits,
+    error::{column_of, Error, Result},
+};
+
+/// Solution for https://adventofcode.com/2024/day/7 - Part One.
+pub fn calibration_result(input: &str) -> Result<usize> {
+    let rows = parse_file(input)?;
+
+    // Test operator combinations - no concatenation
+    sum_valid_rows(&rows, &[Operator::Plus, Operator::Multiply])
+}
+
+/// Solution for https://adventofcode.com/2024/day/7 - Part Two.
+pub fn calibration_result_with_concatenation(input: &str) -> Result<usize> {
+    let rows = parse_file(input)?;
+
+    // Test operator combinations - with concatenation
+    sum_valid_rows(
+        &rows,
+        &[Operator::Plus, Operator::Multiply, Operator::Concatenate],
+    )
+}
+
+fn sum_valid_rows(rows: &[Row], allowed_operators: &[Operator]) -> Result<usize> {
+    let mut valid_sum = 0;
+
+    for row in rows {
+        let is_valid = test_operators(
+            &Operator::Plus,
+            &row.values,
+            0,
+            row.result,
+            allowed_operators,
+        );
+        tracing::trace!(
+            result = row.result,
+            values = row.values.len(),
+            is_valid,
+            "row"
+        );
+        if !is_valid {
+            continue;
+        };
+        valid_sum = usize::checked_add(valid_sum, row.result)
+            .ok_or_else(|| Error::new("Calibration result overflowed usize."))?;
+    }
+
+    Ok(valid_sum)
+}
+
+/// Recursively test different combinations of operators.
+fn test_operators(
+    operator: &Operator,
+    values: &[usize],
+    prev_value: usize,
+    expected_result: usize,
+    allowed_operators: &[Operator],
+) -> bool {
+    // Evaluate on last "iteration"
+    if values.is_empty() {
+        return prev_value == expected_result;
+    }
+
+    // Calculate
+    let current_result = match apply_operator(prev_value, operator, values[0]) {
+        Some(val) => val,
+        None => return false, // overflowed => certainly exceeded the expected result
+    };
+
+    // No need to fork if we already exceeded the expected result.
+    if current_result > expected_result {
+        return false;
+    }
+
+    // Fork with operator variants
+    let sub_values = &values[1..];
+
+    for operator in allowed_operators {
+        if test_operators(
+            operator,
+            sub_values,
+            current_result,
+            expected_result,
+            allowed_operators,
+        ) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Returns `None` on overflow.
+fn apply_operator(val1: usize, operator: &Operator, val2: usize) -> Option<usize> {
+    match operator {
+        Operator::Plus => val1.checked_add(val2),
+        Operator::Multiply => val1.checked_mul(val2),
+        Operator::Concatenate => val1.checked_concat(val2),
+    }
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<Vec<Row>> {
+    let mut rows = vec![];
+
+    for (i, line) in content.lines().enumerate() {
+        let line_num = i + 1;
+
+        let (raw_result, raw_values) = line
+            .split_once(": ")
+            .ok_or_else(|| Error::at_line(line_num, "Expected `result: values`."))?;
+        let result = raw_result
+            .parse::<usize>()
+            .map_err(|_| Error::at(line_num, 1, format!("Invalid result value '{raw_result}'.")))?;
+        let values = raw_values
+            .split_whitespace()
+            .map(|val| {
+                val.parse::<usize>().map_err(|_| {
+                    Error::at(
+                        line_num,
+                        column_of(line, val),
+                        format!("Invalid value '{val}'."),
+                    )
+                })
+            })
+            .collect::<Result<Vec<usize>>>()?;
+
+        if values.is_empty() {
+            return Err(Error::at_line(line_num, "Values missing."));
+        }
+
+        rows.push(Row { result, values });
+    }
+
+    Ok(rows)
+}
+
+#[derive(Clone)]
+struct Row {
+    result: usize,
+    values: Vec<usize>,
+}
+
+#[derive(Clone)]
+enum Operator {
+    Plus,
+    Multiply,
+    Concatenate,
+}
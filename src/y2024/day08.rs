@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+
+use crate::{error::Result, math::gcd};
+
+/// Solution for https://adventofcode.com/2024/day/8 - Part One.
+pub fn antinode_count(input: &str) -> Result<usize> {
+    let (nodes_map, antinode_grid) = parse_file(input);
+
+    Ok(calculate_antinode_count(&nodes_map, antinode_grid, false))
+}
+
+/// Solution for https://adventofcode.com/2024/day/8 - Part Two.
+pub fn repeated_antinode_count(input: &str) -> Result<usize> {
+    let (nodes_map, antinode_grid) = parse_file(input);
+
+    Ok(calculate_antinode_count(&nodes_map, antinode_grid, true))
+}
+
+fn calculate_antinode_count(
+    nodes_map: &NodesMap,
+    mut antinode_grid: AntinodeGrid,
+    repeat: bool,
+) -> usize {
+    // For each node frequency
+    for coords_list in nodes_map.values() {
+        // Test all combinations of nodes
+        for (i, coords1) in coords_list.iter().enumerate() {
+            for coords2 in &coords_list[i + 1..coords_list.len()] {
+                let (mut dist_x, mut dist_y) = coords1.distance(coords2);
+                // Every grid point on the line counts => step by the smallest vector in its direction
+                if repeat {
+                    let divisor =
+                        gcd(dist_x, dist_y).expect("Grid distances are far from overflowing.");
+                    (dist_x, dist_y) = (dist_x / divisor, dist_y / divisor);
+                }
+
+                let mut create_antinodes = |coords: &mut Coordinates, distance: Distance| {
+                    while let Some(val) = coords.safe_add_distance(distance) {
+                        let out_of_bounds =
+                            val.y >= antinode_grid.len() || val.x >= antinode_grid[val.y].len();
+                        if out_of_bounds {
+                            break;
+                        }
+                        antinode_grid[val.y][val.x] = true;
+
+                        if !repeat {
+                            break;
+                        }
+                    }
+                };
+
+                match repeat {
+                    true => {
+                        create_antinodes(&mut coords2.clone(), (-dist_x, -dist_y));
+                        create_antinodes(&mut coords1.clone(), (dist_x, dist_y));
+                    }
+                    false => {
+                        create_antinodes(&mut coords1.clone(), (-dist_x, -dist_y));
+                        create_antinodes(&mut coords2.clone(), (dist_x, dist_y));
+                    }
+                }
+            }
+        }
+    }
+
+    let mut count = 0;
+    for row in antinode_grid {
+        for point in row {
+            if point {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> (NodesMap, AntinodeGrid) {
+    let mut nodes_map: NodesMap = HashMap::new();
+    let mut antinode_grid: AntinodeGrid = vec![];
+
+    for (y, line) in content.lines().enumerate() {
+        let chars: Vec<char> = line.chars().collect();
+
+        antinode_grid.push(vec![false; chars.len()]);
+
+        for (x, char) in chars.into_iter().enumerate() {
+            if char == '.' {
+                continue;
+            }
+
+            match nodes_map.get_mut(&char) {
+                Some(coords_list) => coords_list.push(Coordinates { x, y }),
+                None => {
+                    nodes_map.insert(char, vec![Coordinates { x, y }]);
+                }
+            }
+        }
+    }
+
+    (nodes_map, antinode_grid)
+}
+
+type AntinodeGrid = Vec<Vec<bool>>;
+type NodesMap = HashMap<char, Vec<Coordinates>>;
+type Distance = (isize, isize);
+
+#[derive(Clone)]
+struct Coordinates {
+    x: usize,
+    y: usize,
+}
+
+impl Coordinates {
+    fn distance(&self, coords: &Coordinates) -> Distance {
+        (
+            coords.x_isize() - self.x_isize(),
+            coords.y_isize() - self.y_isize(),
+        )
+    }
+
+    fn x_isize(&self) -> isize {
+        self.x.try_into().unwrap()
+    }
+
+    fn y_isize(&self) -> isize {
+        self.y.try_into().unwrap()
+    }
+
+    fn safe_add_distance(&mut self, distance: Distance) -> Option<&mut Self> {
+        let new_x = self.x_isize() + distance.0;
+        let new_y = self.y_isize() + distance.1;
+
+        if new_x < 0 || new_y < 0 {
+            return None;
+        }
+
+        self.x = new_x as usize;
+        self.y = new_y as usize;
+
+        Some(self)
+    }
+}
@@ -0,0 +1,185 @@
+use std::{collections::VecDeque, ops::Range};
+
+use crate::{
+    error::{Error, Result},
+    range_set::RangeSet,
+};
+
+/// Solution for https://adventofcode.com/2024/day/9 - Part One.
+pub fn checksum_by_block(input: &str) -> Result<usize> {
+    let (disk, _) = parse_file(input)?;
+
+    Ok(get_checksum(&defragment_by_block(disk)))
+}
+
+/// Solution for https://adventofcode.com/2024/day/9 - Part Two.
+pub fn checksum_by_file(input: &str) -> Result<usize> {
+    let (disk, fragments) = parse_file(input)?;
+
+    Ok(get_checksum(&defragment_by_file(disk, fragments)))
+}
+
+fn defragment_by_block(mut disk: Disk) -> Disk {
+    // Create a list of free space indexes
+    let mut free_space_list = VecDeque::new();
+
+    for (i, block) in disk.iter().enumerate() {
+        match block {
+            DiskBlock::Free => free_space_list.push_back(i),
+            DiskBlock::File(_) => (),
+        }
+    }
+
+    // Prepare all moves
+    struct Move {
+        from: usize,
+        to: usize,
+    }
+    let mut move_buffer: Vec<Move> = vec![];
+    for (block_index, block) in disk.iter().enumerate().rev() {
+        if free_space_list.is_empty() {
+            break;
+        }
+
+        match block {
+            DiskBlock::Free => continue,
+            DiskBlock::File(_) => {
+                let free_index = free_space_list.pop_front().unwrap();
+                if free_index > block_index {
+                    break;
+                }
+                move_buffer.push(Move {
+                    from: block_index,
+                    to: free_index,
+                });
+            }
+        }
+    }
+
+    // Execute all prepared moves
+    for move_action in move_buffer {
+        let block = std::mem::replace(&mut disk[move_action.from], DiskBlock::Free);
+        disk[move_action.to] = block;
+    }
+
+    disk
+}
+
+fn defragment_by_file(mut disk: Disk, fragments: Vec<Fragment>) -> Disk {
+    // Split fragments to files and free space
+    let mut file_fragments: Vec<Fragment> = vec![];
+    let mut free_space: RangeSet<usize> = RangeSet::new();
+
+    for fragment in fragments {
+        match fragment.block_type {
+            DiskBlock::File(_) => file_fragments.push(fragment),
+            DiskBlock::Free => free_space.insert(fragment.range()),
+        }
+    }
+
+    // Iterate files from back
+    for file_fragment in file_fragments.iter().rev() {
+        // Move to first free space where the file can fit - only to the left
+        let Some(free_range) = free_space.first_fit(file_fragment.length) else {
+            continue;
+        };
+        if free_range.start > file_fragment.start_index {
+            continue;
+        }
+
+        // Move individual blocks
+        for i in 0..file_fragment.length {
+            let block =
+                std::mem::replace(&mut disk[file_fragment.start_index + i], DiskBlock::Free);
+            disk[free_range.start + i] = block;
+        }
+
+        free_space.remove(free_range.start..free_range.start + file_fragment.length);
+        free_space.insert(file_fragment.range());
+    }
+
+    disk
+}
+
+fn get_checksum(disk: &Disk) -> usize {
+    let mut checksum = 0;
+
+    for (i, block) in disk.iter().enumerate() {
+        if let DiskBlock::File(id) = block {
+            checksum += id * i;
+        }
+    }
+
+    checksum
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<(Disk, Vec<Fragment>)> {
+    let mut disk: Disk = vec![];
+    let mut fragments: Vec<Fragment> = vec![];
+
+    let mut is_file = true;
+    let mut file_id = 0;
+    let mut start_index = 0;
+    for (i, character) in content.trim_end().chars().enumerate() {
+        let count = character.to_digit(10).ok_or_else(|| {
+            Error::at(
+                1,
+                i + 1,
+                format!("Invalid character '{character}', digit expected."),
+            )
+        })? as usize;
+
+        let block = match is_file {
+            true => DiskBlock::File(file_id),
+            false => DiskBlock::Free,
+        };
+
+        for _ in 0..count {
+            disk.push(block.clone());
+        }
+
+        if count > 0 {
+            fragments.push(Fragment {
+                block_type: block,
+                length: count,
+                start_index,
+            });
+        }
+
+        if is_file {
+            file_id += 1;
+        }
+        is_file = !is_file;
+        start_index += count;
+    }
+
+    tracing::debug!(blocks = disk.len(), fragments = fragments.len(), "parsed");
+
+    Ok((disk, fragments))
+}
+
+type Disk = Vec<DiskBlock>;
+type FileId = usize;
+
+#[derive(Clone)]
+enum DiskBlock {
+    File(FileId),
+    Free,
+}
+struct Fragment {
+    block_type: DiskBlock,
+    length: usize,
+    start_index: usize,
+}
+
+impl Fragment {
+    fn range(&self) -> Range<usize> {
+        self.start_index..self.start_index + self.length
+    }
+}
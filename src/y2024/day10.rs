@@ -0,0 +1,157 @@
+use std::collections::HashSet;
+
+use crate::{
+    error::{Error, Result},
+    params::Params,
+};
+
+/// Height of trailheads and summits if not set by parameters.
+const TRAILHEAD_HEIGHT: u8 = 0;
+const SUMMIT_HEIGHT: u8 = 9;
+
+pub const PARAMETERS: [&str; 2] = ["trailhead_height", "summit_height"];
+
+/// Solution for https://adventofcode.com/2024/day/10 - Part One.
+pub fn score_sum(input: &str, params: &Params) -> Result<usize> {
+    let (score_sum, _) = calculate_trailheads(input, params)?;
+
+    Ok(score_sum)
+}
+
+/// Solution for https://adventofcode.com/2024/day/10 - Part Two.
+pub fn rating_sum(input: &str, params: &Params) -> Result<usize> {
+    let (_, rating_sum) = calculate_trailheads(input, params)?;
+
+    Ok(rating_sum)
+}
+
+/// Returns tuple of (score sum, rating sum).
+fn calculate_trailheads(input: &str, params: &Params) -> Result<(usize, usize)> {
+    let grid = parse_file(input)?;
+    let trailhead_height = params.get("trailhead_height", TRAILHEAD_HEIGHT)?;
+    let summit_height = params.get("summit_height", SUMMIT_HEIGHT)?;
+
+    if trailhead_height > summit_height {
+        return Err(Error::new(format!(
+            "Trailhead height {trailhead_height} is above summit height {summit_height}."
+        )));
+    }
+
+    let mut score_sum = 0;
+    let mut rating_sum = 0;
+
+    for (y, row) in grid.rows.iter().enumerate() {
+        for (x, height) in row.iter().enumerate() {
+            if *height != trailhead_height {
+                continue;
+            }
+
+            let mut mountain_peaks = HashSet::new();
+            test_position(
+                &grid,
+                (x, y),
+                trailhead_height,
+                summit_height,
+                &mut mountain_peaks,
+                &mut rating_sum,
+            );
+            score_sum += mountain_peaks.len();
+        }
+    }
+
+    tracing::debug!(score_sum, rating_sum, "trailheads calculated");
+
+    Ok((score_sum, rating_sum))
+}
+
+fn test_position(
+    grid: &Grid,
+    (x, y): Coordinates,
+    height: u8,
+    max_height: u8,
+    mountain_peaks: &mut HashSet<Coordinates>,
+    rating_sum: &mut usize,
+) {
+    // Reached top => increase score
+    if height == max_height {
+        mountain_peaks.insert((x, y));
+        *rating_sum += 1;
+        return;
+    }
+
+    // Try each direction (whether an increment of current height)
+    let mut try_direction = |coords: Coordinates| {
+        if let Some(new_height) = grid.at(coords) {
+            if new_height == height + 1 {
+                test_position(
+                    grid,
+                    coords,
+                    height + 1,
+                    max_height,
+                    mountain_peaks,
+                    rating_sum,
+                );
+            }
+        }
+    };
+
+    // Left
+    if x > 0 {
+        try_direction((x - 1, y));
+    }
+
+    // Right
+    try_direction((x + 1, y));
+
+    // Up
+    if y > 0 {
+        try_direction((x, y - 1));
+    }
+
+    // Down
+    try_direction((x, y + 1));
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<Grid> {
+    let mut rows = vec![];
+
+    for (y, line) in content.lines().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(x, val)| match val.to_digit(10) {
+                Some(height) => Ok(height as u8), // single digit always fits
+                None => Err(Error::at(
+                    y + 1,
+                    x + 1,
+                    format!("Invalid height '{val}', digit expected."),
+                )),
+            })
+            .collect::<Result<Vec<u8>>>()?;
+
+        rows.push(row);
+    }
+
+    Ok(Grid { rows })
+}
+
+type Coordinates = (usize, usize);
+struct Grid {
+    rows: Vec<Vec<u8>>,
+}
+
+impl Grid {
+    fn at(&self, (x, y): Coordinates) -> Option<u8> {
+        if y >= self.rows.len() || x >= self.rows[y].len() {
+            return None;
+        }
+
+        Some(self.rows[y][x])
+    }
+}
@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use crate::{
+    digits::Digits,
+    error::{column_of, Error, Result},
+};
+
+/// Blink count for https://adventofcode.com/2024/day/11 - Part One.
+pub const PART_ONE_BLINKS: usize = 25;
+/// Blink count for https://adventofcode.com/2024/day/11 - Part Two.
+pub const PART_TWO_BLINKS: usize = 75;
+
+/// Blink counts can be overridden, the examples use different ones.
+pub const PARAMETERS: [&str; 2] = ["part_one_blinks", "part_two_blinks"];
+
+/// Solution for https://adventofcode.com/2024/day/11 - Part One & Two.
+/// Stone count after the specified number of blinks.
+pub fn stone_count(input: &str, blinks: usize) -> Result<usize> {
+    let stones = parse_file(input)?;
+    let mut cache: Cache = HashMap::new();
+
+    let mut stone_count = 0;
+    for stone in &stones {
+        stone_count = add_counts(stone_count, get_stone_count(*stone, 0, blinks, &mut cache)?)?;
+    }
+
+    // Recursion summary - distinct stone values seen, (value, remaining blinks) results cached
+    tracing::debug!(
+        stones = stones.len(),
+        distinct_values = cache.len(),
+        cached_counts = cache.values().map(|item| item.len()).sum::<usize>(),
+        "blinking finished"
+    );
+
+    Ok(stone_count)
+}
+
+fn get_stone_count(
+    stone_value: usize,
+    depth: usize,
+    max_depth: usize,
+    cache: &mut Cache,
+) -> Result<usize> {
+    // Reached last "iteration"
+    if depth == max_depth {
+        return Ok(1);
+    }
+
+    // Try to find value in cache
+    if let Some(cache_item) = cache.get(&stone_value) {
+        if let Some(cached_stone_count) = cache_item.get(&(max_depth - depth)) {
+            return Ok(*cached_stone_count);
+        }
+    }
+
+    // Recursively calculate count if not cached
+    let mut apply_recursion = |value| get_stone_count(value, depth + 1, max_depth, cache);
+
+    let stone_count = if stone_value == 0 {
+        apply_recursion(1)?
+    } else if stone_value.digit_count().is_multiple_of(2) {
+        let (left, right) = stone_value.split_at_digit(stone_value.digit_count() / 2);
+        add_counts(apply_recursion(left)?, apply_recursion(right)?)?
+    } else {
+        let multiplied_value = stone_value.checked_mul(2024).ok_or_else(|| {
+            Error::new(format!(
+                "Stone value {stone_value} * 2024 overflowed usize."
+            ))
+        })?;
+        apply_recursion(multiplied_value)?
+    };
+
+    // Save calculated count to cache
+    match cache.get_mut(&stone_value) {
+        Some(cache_item) => {
+            cache_item.insert(max_depth - depth, stone_count);
+        }
+        None => {
+            cache.insert(
+                stone_value,
+                HashMap::from([(max_depth - depth, stone_count)]),
+            );
+        }
+    };
+
+    Ok(stone_count)
+}
+
+fn add_counts(count1: usize, count2: usize) -> Result<usize> {
+    count1
+        .checked_add(count2)
+        .ok_or_else(|| Error::new("Stone count overflowed usize."))
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<Vec<usize>> {
+    let mut stones = vec![];
+
+    for (i, line) in content.lines().enumerate() {
+        let line_num = i + 1;
+
+        for val in line.split_whitespace() {
+            let stone = val.parse::<usize>().map_err(|_| {
+                Error::at(
+                    line_num,
+                    column_of(line, val),
+                    format!("Invalid stone value '{val}'."),
+                )
+            })?;
+            stones.push(stone);
+        }
+    }
+
+    Ok(stones)
+}
+
+type CacheItem = HashMap<usize, usize>;
+type Cache = HashMap<usize, CacheItem>;
@@ -0,0 +1,204 @@
+use crate::{
+    error::{Error, Result},
+    linear::{self, Solution},
+    math::extended_gcd,
+    params::Params,
+    text::{blocks, ints},
+};
+
+const A_TOKEN_PRICE: isize = 3;
+const B_TOKEN_PRICE: isize = 1;
+const PRIZE_OFFSET: u64 = 10000000000000;
+
+pub const PARAMETERS: [&str; 3] = ["a_token_price", "b_token_price", "prize_offset"];
+
+/// Solution for https://adventofcode.com/2024/day/13 - Part One.
+pub fn token_price(input: &str, params: &Params) -> Result<isize> {
+    let machines = parse_file(input)?;
+
+    get_token_price(&machines, params)
+}
+
+/// Solution for https://adventofcode.com/2024/day/13 - Part Two.
+pub fn token_price_increased_distance(input: &str, params: &Params) -> Result<isize> {
+    let mut machines = parse_file(input)?;
+    let prize_offset = params.get("prize_offset", PRIZE_OFFSET)?;
+
+    for machine in machines.iter_mut() {
+        let increase = |coord: u64| {
+            coord.checked_add(prize_offset).ok_or_else(|| {
+                Error::at_line(machine.line_num, "Prize coordinates overflowed u64.")
+            })
+        };
+        machine.prize_coords = (
+            increase(machine.prize_coords.0)?,
+            increase(machine.prize_coords.1)?,
+        );
+    }
+
+    get_token_price(&machines, params)
+}
+
+fn get_token_price(machines: &[Machine], params: &Params) -> Result<isize> {
+    let a_token_price = params.get("a_token_price", A_TOKEN_PRICE)?;
+    let b_token_price = params.get("b_token_price", B_TOKEN_PRICE)?;
+    let mut token_price: isize = 0;
+
+    for machine in machines {
+        let presses = cheapest_presses(machine, a_token_price, b_token_price)
+            .map_err(|err| Error::at_line(machine.line_num, err.description))?;
+        let Some((a_count, b_count)) = presses else {
+            continue;
+        };
+
+        // Increase token price sum
+        tracing::trace!(line = machine.line_num, a_count, b_count, "machine solved");
+        token_price = a_count
+            .checked_mul(a_token_price)
+            .zip(b_count.checked_mul(b_token_price))
+            .and_then(|(a_price, b_price)| a_price.checked_add(b_price))
+            .and_then(|price| token_price.checked_add(price))
+            .ok_or_else(|| Error::at_line(machine.line_num, "Token price overflowed isize."))?;
+    }
+
+    Ok(token_price)
+}
+
+/// Cheapest non-negative integer (A, B) button presses reaching the prize, `None` if there are none.
+fn cheapest_presses(
+    machine: &Machine,
+    a_token_price: isize,
+    b_token_price: isize,
+) -> Result<Option<(isize, isize)>> {
+    let widen = |(x, y): (u64, u64)| [i128::from(x), i128::from(y)];
+    let (a, b, prize) = (
+        widen(machine.a_button),
+        widen(machine.b_button),
+        widen(machine.prize_coords),
+    );
+    let to_isize = |count: i128| {
+        isize::try_from(count)
+            .map_err(|_| Error::new(format!("Press count {count} overflowed isize.")))
+    };
+
+    // Solve as equations with two variables
+    let presses = match linear::solve([[a[0], b[0]], [a[1], b[1]]], prize)? {
+        Solution::Unique([a_count, b_count]) => a_count.to_integer().zip(b_count.to_integer()),
+        Solution::None => None,
+        // Buttons move in the prize's direction - any combination on the line
+        Solution::Infinite => {
+            let axis = usize::from(a[0] == 0 && b[0] == 0);
+            cheapest_on_line(
+                (a[axis], b[axis], prize[axis]),
+                (a_token_price as i128, b_token_price as i128),
+            )?
+        }
+    };
+
+    match presses {
+        Some((a_count, b_count)) if a_count >= 0 && b_count >= 0 => {
+            Ok(Some((to_isize(a_count)?, to_isize(b_count)?)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Cheapest non-negative integer solution of `a * x + b * y = target` (Diophantine), `None` if there is none.
+/// Coefficients and prices are non-negative.
+fn cheapest_on_line(
+    (a, b, target): (i128, i128, i128),
+    (a_price, b_price): (i128, i128),
+) -> Result<Option<(i128, i128)>> {
+    let overflow = || Error::new("Press count arithmetic overflowed i128.");
+
+    let (divisor, x, y) = extended_gcd(a, b).ok_or_else(overflow)?;
+    if divisor == 0 {
+        return Ok((target == 0).then_some((0, 0)));
+    }
+    if target % divisor != 0 {
+        return Ok(None);
+    }
+
+    // All solutions are (x0 + t * b', y0 - t * a') - the price is linear in t => cheapest at an end of the range
+    let scale = target / divisor;
+    let x0 = x.checked_mul(scale).ok_or_else(overflow)?;
+    let y0 = y.checked_mul(scale).ok_or_else(overflow)?;
+    let (a_step, b_step) = (a / divisor, b / divisor);
+    let solution = |t: i128| {
+        let x = t.checked_mul(b_step).and_then(|step| x0.checked_add(step));
+        let y = t.checked_mul(a_step).and_then(|step| y0.checked_sub(step));
+        x.zip(y).ok_or_else(overflow)
+    };
+
+    // x0 + t * b' >= 0 and y0 - t * a' >= 0, a zero step leaves the range open on that side
+    let low = (b_step > 0).then(|| -x0.div_euclid(b_step));
+    let high = (a_step > 0).then(|| y0.div_euclid(a_step));
+    if let (Some(low), Some(high)) = (low, high) {
+        if low > high {
+            return Ok(None);
+        }
+    }
+
+    let price = |(x, y): (i128, i128)| {
+        x.checked_mul(a_price)
+            .zip(y.checked_mul(b_price))
+            .and_then(|(x_price, y_price)| x_price.checked_add(y_price))
+            .ok_or_else(overflow)
+    };
+    let mut cheapest: Option<((i128, i128), i128)> = None;
+    for t in [low, high].into_iter().flatten() {
+        let candidate = solution(t)?;
+        let candidate_price = price(candidate)?;
+        if cheapest.is_none_or(|(_, cheapest_price)| candidate_price < cheapest_price) {
+            cheapest = Some((candidate, candidate_price));
+        }
+    }
+
+    Ok(cheapest.map(|(presses, _)| presses))
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<Vec<Machine>> {
+    let mut machines: Vec<Machine> = vec![];
+
+    for (line_num, lines) in blocks(content) {
+        let [a_line, b_line, prize_line] = lines[..] else {
+            return Err(Error::at_line(
+                line_num,
+                "Machine must have button A, button B and prize lines.",
+            ));
+        };
+
+        // Exactly two numbers (X and Y) on each line
+        let coords = |offset: usize, line: &str| {
+            ints::<u64, 2>(line)
+                .map(|[x, y]| (x, y))
+                .map_err(|err| err.with_line(line_num + offset))
+        };
+
+        machines.push(Machine {
+            a_button: coords(0, a_line)?,
+            b_button: coords(1, b_line)?,
+            prize_coords: coords(2, prize_line)?,
+            line_num,
+        });
+    }
+
+    tracing::debug!(machines = machines.len(), "parsed");
+
+    Ok(machines)
+}
+
+#[derive(Clone)]
+struct Machine {
+    a_button: (u64, u64),
+    b_button: (u64, u64),
+    prize_coords: (u64, u64),
+    /// First line of the machine in input
+    line_num: usize,
+}
@@ -0,0 +1,312 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+    animation::{Recording, Snapshot},
+    error::{Error, Result},
+    math::{lcm, mul_mod},
+    params::Params,
+    text::ints_with_columns,
+    Coordinates, Distance, Grid,
+};
+
+pub(crate) const X_LEN: usize = 101;
+pub(crate) const Y_LEN: usize = 103;
+const ITER_COUNT: usize = 100;
+const LOOKUP_DIRECTIONS: [Distance; 4] = [
+    (1, 0),   // right
+    (-1, -1), // down left
+    (0, -1),  // down middle
+    (1, -1),  // down right
+];
+const HEURISTIC_THRESHOLD: usize = 300;
+
+/// Room size, iteration count and image heuristic can be overridden - the example room is 11x7.
+pub const PARAMETERS: [&str; 4] = ["x_len", "y_len", "iter_count", "heuristic_threshold"];
+
+/// Solution for https://adventofcode.com/2024/day/14 - Part One.
+pub fn safety_factor(input: &str, params: &Params) -> Result<usize> {
+    let room = Room::from_params(params)?;
+    let iter_count = params.get("iter_count", ITER_COUNT)?;
+    let mut robots = parse_file(input, &room)?;
+
+    for robot in &mut robots {
+        robot.position = get_new_position(robot, iter_count, &room);
+    }
+
+    Ok(get_safety_factor(&robots, &room))
+}
+
+/// Solution for https://adventofcode.com/2024/day/14 - Part Two.
+/// Iteration count after which the robots form the Easter egg.
+pub fn easter_egg_iterations(input: &str, params: &Params) -> Result<usize> {
+    let room = Room::from_params(params)?;
+    let heuristic_threshold = params.get("heuristic_threshold", HEURISTIC_THRESHOLD)?;
+    let mut robots = parse_file(input, &room)?;
+
+    // Each robot is back at its start after `x_len` steps horizontally and `y_len` vertically, so all positions
+    // repeat after their lcm - no image later if there is none within it. Room sizes fit isize.
+    let period = lcm(room.x_len as isize, room.y_len as isize)
+        .ok_or_else(|| Error::new("Period of the room overflowed isize."))?
+        as usize;
+    tracing::debug!(period, "robots period");
+
+    for counter in 1..=period {
+        // Move robots by one iteration
+        for robot in &mut robots {
+            robot.position = get_new_position(robot, 1, &room);
+        }
+
+        // Search for image
+        // Heuristic - anything that resembles some kind of image will very likely have many neighbouring points forming continuous lines or shapes.
+        let score = get_continuity_score(&create_grid(&robots, &room), &robots);
+        tracing::trace!(iteration = counter, score, "continuity score");
+        if score > heuristic_threshold {
+            tracing::debug!(iteration = counter, score, "image found");
+            return Ok(counter);
+        };
+    }
+
+    Err(Error::new(format!(
+        "No image found within the robots' period of {period} iterations."
+    )))
+}
+
+/// Picture formed by the robots after specified number of iterations.
+pub fn render_robots(input: &str, params: &Params, iter_count: usize) -> Result<String> {
+    let room = Room::from_params(params)?;
+    let mut robots = parse_file(input, &room)?;
+
+    for robot in &mut robots {
+        robot.position = get_new_position(robot, iter_count, &room);
+    }
+
+    Ok(render_grid(&create_grid(&robots, &room)))
+}
+
+/// Robot positions after each recorded iteration, see `crate::animation`.
+pub fn record_robots(input: &str, params: &Params, recording: &Recording) -> Result<Vec<Snapshot>> {
+    let room = Room::from_params(params)?;
+    let robots = parse_file(input, &room)?;
+
+    let snapshots = recording
+        .iterations()
+        .map(|iteration| {
+            let moved_robots: Vec<Robot> = robots
+                .iter()
+                .map(|robot| Robot {
+                    position: get_new_position(robot, iteration, &room),
+                    velocity: robot.velocity,
+                })
+                .collect();
+
+            let mut rows = vec![vec!['.'; room.x_len]; room.y_len];
+            for robot in &moved_robots {
+                rows[robot.position.y][robot.position.x] = 'X';
+            }
+
+            let grid = create_grid(&moved_robots, &room);
+            Snapshot::new(iteration, Grid::new(rows))
+                .with_metric("safety factor", get_safety_factor(&moved_robots, &room))
+                .with_metric(
+                    "continuity score",
+                    get_continuity_score(&grid, &moved_robots),
+                )
+        })
+        .collect();
+
+    Ok(snapshots)
+}
+
+/// Product of robot counts in the quadrants.
+fn get_safety_factor(robots: &[Robot], room: &Room) -> usize {
+    let mut quadrant_counts: HashMap<usize, usize> =
+        HashMap::from([(0, 0), (1, 0), (2, 0), (3, 0)]);
+
+    for robot in robots {
+        let quadrant = match find_quadrant(robot.position.clone(), room) {
+            Some(val) => val,
+            None => continue,
+        };
+
+        let quadrant_count = quadrant_counts.get_mut(&quadrant).unwrap();
+        *quadrant_count += 1;
+    }
+
+    quadrant_counts.into_values().product()
+}
+
+/// Create robots grid
+fn create_grid(robots: &[Robot], room: &Room) -> RobotGrid {
+    let mut grid = Grid::new(vec![vec![GridPoint::Empty; room.x_len]; room.y_len]);
+    mark_grid_positions(robots, &mut grid);
+
+    grid
+}
+
+/// Get coordinates after specified number of iterations. O(1) complexity.
+fn get_new_position(robot: &Robot, iter_count: usize, room: &Room) -> Coordinates {
+    // Room sizes fit isize (checked in `Room::from_params`) - positions repeat after `len` iterations
+    let move_along = |position: usize, velocity: isize, len: usize| {
+        let len_signed = len as isize;
+        let shift = mul_mod(velocity, (iter_count % len) as isize, len_signed)
+            .expect("Room size is positive.");
+
+        (position + shift as usize) % len
+    };
+
+    Coordinates::new((
+        move_along(robot.position.x, robot.velocity.0, room.x_len),
+        move_along(robot.position.y, robot.velocity.1, room.y_len),
+    ))
+}
+
+/// Place robots on an empty grid.
+fn mark_grid_positions(robots: &[Robot], grid: &mut RobotGrid) {
+    for robot in robots {
+        *grid.at_mut(&robot.position).unwrap() = GridPoint::Robot;
+    }
+}
+
+/// Get score based on neighbouring points (each neighbour increments score by 1).
+fn get_continuity_score(grid: &RobotGrid, robots: &[Robot]) -> usize {
+    let mut score = 0;
+
+    for robot in robots {
+        for direction in LOOKUP_DIRECTIONS {
+            if let Some(coords) = robot.position.clone().safe_add_distance(direction) {
+                if let Some(point) = grid.at(&coords) {
+                    if *point == GridPoint::Robot {
+                        score += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    score
+}
+
+fn render_grid(grid: &RobotGrid) -> String {
+    let mut picture = String::new();
+
+    for row in grid.get_rows() {
+        for point in row {
+            match point {
+                GridPoint::Robot => picture.push('X'),
+                GridPoint::Empty => picture.push(' '),
+            }
+        }
+        picture.push('\n');
+    }
+
+    picture
+}
+
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content, &Room::default());
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str, room: &Room) -> Result<Vec<Robot>> {
+    let mut robots = vec![];
+
+    for (i, line) in content.lines().enumerate() {
+        let line_num = i + 1;
+
+        // `p=x,y v=x,y`
+        let [x, y, (velocity_x, _), (velocity_y, _)] =
+            ints_with_columns::<isize, 4>(line).map_err(|err| err.with_line(line_num))?;
+
+        // Validate - position must be within grid
+        let validate_position =
+            |(value, column): (isize, usize), len: usize| match usize::try_from(value) {
+                Ok(val) if val < len => Ok(val),
+                _ => Err(Error::at(
+                    line_num,
+                    column,
+                    format!("Position {value} out of grid bounds 0..{len}."),
+                )),
+            };
+
+        let position = Coordinates::new((
+            validate_position(x, room.x_len)?,
+            validate_position(y, room.y_len)?,
+        ));
+        let velocity = (velocity_x, velocity_y);
+
+        robots.push(Robot { position, velocity });
+    }
+
+    tracing::debug!(robots = robots.len(), "parsed");
+
+    Ok(robots)
+}
+
+fn find_quadrant(coords: Coordinates, room: &Room) -> Option<usize> {
+    match (
+        coords.x.cmp(&((room.x_len - 1) / 2)),
+        coords.y.cmp(&((room.y_len - 1) / 2)),
+    ) {
+        (Ordering::Equal, _) => None,
+        (_, Ordering::Equal) => None,
+        (Ordering::Less, Ordering::Less) => Some(0),
+        (Ordering::Greater, Ordering::Less) => Some(1),
+        (Ordering::Less, Ordering::Greater) => Some(2),
+        (Ordering::Greater, Ordering::Greater) => Some(3),
+    }
+}
+
+struct Room {
+    x_len: usize,
+    y_len: usize,
+}
+
+impl Room {
+    fn from_params(params: &Params) -> Result<Self> {
+        let room = Room {
+            x_len: params.get("x_len", X_LEN)?,
+            y_len: params.get("y_len", Y_LEN)?,
+        };
+
+        // Positions are moved in isize arithmetic
+        for len in [room.x_len, room.y_len] {
+            if isize::try_from(len).is_err() {
+                return Err(Error::new(format!("Room size {len} doesn't fit isize.")));
+            }
+        }
+
+        // find_quadrant() does not take into account even number of positions.
+        if room.x_len.is_multiple_of(2) || room.y_len.is_multiple_of(2) {
+            return Err(Error::new(format!(
+                "Room size {}x{} must be odd in both dimensions.",
+                room.x_len, room.y_len
+            )));
+        }
+
+        Ok(room)
+    }
+}
+
+impl Default for Room {
+    fn default() -> Self {
+        Room {
+            x_len: X_LEN,
+            y_len: Y_LEN,
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct Robot {
+    position: Coordinates,
+    velocity: Distance,
+}
+
+#[derive(Clone, PartialEq)]
+enum GridPoint {
+    Robot,
+    Empty,
+}
+
+type RobotGrid = Grid<GridPoint>;
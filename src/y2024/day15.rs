@@ -0,0 +1,446 @@
+use crate::{
+    animation::{Recording, Snapshot},
+    error::{Error, Result},
+    Coordinates, Direction, Distance, Grid,
+};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Solution for https://adventofcode.com/2024/day/15 - Part One.
+/// This solution could be heavily optimized and made more generic, it is essentially a simple game/physics engine.
+pub fn gps_sum(input: &str) -> Result<usize> {
+    let (mut grid, movement_instructions, robot_coords) = parse_file(input)?;
+
+    apply_movement_instructions(&movement_instructions, &mut grid, robot_coords, |_, _| ());
+
+    Ok(calculate_result(&grid))
+}
+
+/// Solution for https://adventofcode.com/2024/day/15 - Part Two.
+pub fn gps_sum_enlarged(input: &str) -> Result<usize> {
+    let (grid, movement_instructions, robot_coords) = parse_file(input)?;
+    let mut enlarged_grid = create_enlarged_grid(&grid);
+    let robot_coords_enlarged = Coordinates::new((robot_coords.x * 2, robot_coords.y));
+
+    apply_movement_instructions(
+        &movement_instructions,
+        &mut enlarged_grid,
+        robot_coords_enlarged,
+        |_, _| (),
+    );
+
+    Ok(calculate_result(&enlarged_grid))
+}
+
+/// Warehouse after each recorded move (of the enlarged warehouse for Part Two), see `crate::animation`.
+pub fn record_moves(input: &str, enlarged: bool, recording: &Recording) -> Result<Vec<Snapshot>> {
+    let (mut grid, movement_instructions, mut robot_coords) = parse_file(input)?;
+    if enlarged {
+        grid = create_enlarged_grid(&grid);
+        robot_coords = Coordinates::new((robot_coords.x * 2, robot_coords.y));
+    }
+
+    let snapshot = |move_count: usize, grid: &PointGrid| {
+        Snapshot::from_text(move_count, &render_grid(grid))
+            .with_metric("gps sum", calculate_result(grid))
+            .with_metric("moves left", movement_instructions.len() - move_count)
+    };
+
+    let mut snapshots = vec![];
+    let mut record = |move_count: usize, grid: &PointGrid| {
+        if recording.is_recorded(move_count) && snapshots.len() < recording.frames {
+            snapshots.push(snapshot(move_count, grid));
+        }
+    };
+
+    record(0, &grid);
+    apply_movement_instructions(&movement_instructions, &mut grid, robot_coords, &mut record);
+
+    // Always show the end of the moves
+    let move_count = movement_instructions.len();
+    if snapshots.len() < recording.frames
+        && snapshots
+            .last()
+            .is_none_or(|last| last.iteration != move_count)
+    {
+        snapshots.push(snapshot(move_count, &grid));
+    }
+
+    Ok(snapshots)
+}
+
+fn apply_movement_instructions(
+    movement_instructions: &MovementInstructions,
+    grid: &mut PointGrid,
+    mut robot_coords: Coordinates,
+    mut on_move: impl FnMut(usize, &PointGrid),
+) {
+    let mut blocked_count = 0;
+
+    for (i, instruction) in movement_instructions.iter().enumerate() {
+        // Called with the number of moves done, for blocked moves too
+        let mut on_move = |grid: &PointGrid| on_move(i + 1, grid);
+
+        let step_distance = instruction.step_distance();
+        let new_coords = robot_coords
+            .clone()
+            .safe_add_distance(step_distance)
+            .expect("Can't be out of bounds - wall around grid.");
+        let target_point = grid
+            .at(&new_coords)
+            .expect("Can't be out of bounds - wall around grid.");
+
+        match target_point {
+            // Move to empty space
+            Point::Empty => (),
+            // Obstacle => do nothing
+            Point::Obstacle => {
+                blocked_count += 1;
+                tracing::trace!(?instruction, robot = ?robot_coords, "blocked by wall");
+                on_move(grid);
+                continue;
+            }
+            // Try to move box(es)
+            Point::Box(box_ref) => {
+                // Find boxes and check if they can be moved
+                let mut boxes_to_move = vec![];
+                if !can_move_box_recursive(box_ref, instruction, grid, &mut boxes_to_move) {
+                    blocked_count += 1;
+                    tracing::trace!(?instruction, robot = ?robot_coords, "blocked by boxes");
+                    on_move(grid);
+                    continue;
+                }
+                tracing::trace!(?instruction, boxes = boxes_to_move.len(), "pushing boxes");
+
+                // Move boxes
+                for box_to_move in boxes_to_move {
+                    for coords in box_to_move.borrow().get_all_coords() {
+                        let old_box_point = grid.at_mut(&coords).expect("Should always exist");
+                        *old_box_point = Point::Empty;
+                    }
+
+                    box_to_move
+                        .borrow_mut()
+                        .move_by(step_distance)
+                        .expect("Should always be able to move");
+
+                    for coords in box_to_move.borrow().get_all_coords() {
+                        let new_box_point = grid.at_mut(&coords).expect("Should always exist");
+                        *new_box_point = Point::Box(Rc::clone(&box_to_move));
+                    }
+                }
+            }
+            Point::Robot => unreachable!("Multiple robots are rejected by parse_file()."),
+        }
+
+        // Move robot
+        *grid.at_mut(&robot_coords).unwrap() = Point::Empty;
+        robot_coords = new_coords;
+        *grid.at_mut(&robot_coords).unwrap() = Point::Robot;
+        tracing::trace!(?instruction, robot = ?robot_coords, "moved");
+        on_move(grid);
+    }
+
+    tracing::debug!(
+        moves = movement_instructions.len(),
+        blocked = blocked_count,
+        "movement finished\n{}",
+        render_grid(grid)
+    );
+}
+
+/// This function is quite inefficient (duplicate searches, edge_coords could be done with an iterator, etc.)
+/// and could be heavily optimized.
+fn can_move_box_recursive(
+    target_box: &BoxRef,
+    instruction: &Direction,
+    grid: &PointGrid,
+    boxes_to_move: &mut Vec<BoxRef>,
+) -> bool {
+    let step_distance = instruction.step_distance();
+    let box_edge = target_box.borrow().get_edge_coords(instruction);
+
+    for edge_coords in &box_edge {
+        let lookup_coords = edge_coords
+            .clone()
+            .safe_add_distance(step_distance)
+            .expect("Can't be out of bounds - wall around grid.");
+        match grid
+            .at(&lookup_coords)
+            .expect("Can't be out of bounds - wall around grid.")
+        {
+            Point::Empty => (),
+            Point::Obstacle => return false,
+            Point::Box(next_box) => {
+                if !can_move_box_recursive(next_box, instruction, grid, boxes_to_move) {
+                    return false;
+                }
+            }
+            Point::Robot => unreachable!("Multiple robots are rejected by parse_file()."),
+        }
+    }
+
+    if !boxes_to_move.contains(target_box) {
+        boxes_to_move.push(Rc::clone(target_box)); // Temporarily "move" ownership out of grid while boxes are being moved
+    }
+
+    true
+}
+
+fn calculate_result(grid: &PointGrid) -> usize {
+    let mut result = 0;
+
+    for (y, row) in grid.get_rows().iter().enumerate() {
+        for (x, point) in row.iter().enumerate() {
+            match point {
+                Point::Box(box_ref) => {
+                    if box_ref.borrow().origin != Coordinates::new((x, y)) {
+                        continue;
+                    }
+                }
+                _ => continue,
+            }
+
+            result += 100 * y + x;
+        }
+    }
+
+    result
+}
+
+/// returns tuple of (grid, movement instructions, robot starting position)
+/// Entry point for `crate::fuzz` - the parser must never panic.
+pub(crate) fn fuzz_parse_file(content: &str) {
+    let _ = parse_file(content);
+}
+
+#[tracing::instrument(level = "debug", skip_all)]
+fn parse_file(content: &str) -> Result<(PointGrid, MovementInstructions, Coordinates)> {
+    let mut rows = vec![];
+    let mut move_instructions = vec![];
+    let mut robot_coords = None;
+
+    let mut line_iterator = content.lines().enumerate();
+
+    for (y, line) in line_iterator.by_ref() {
+        if line.is_empty() {
+            break;
+        }
+
+        let mut row = vec![];
+
+        for (x, char) in line.chars().enumerate() {
+            let point = match char {
+                '#' => Point::Obstacle,
+                'O' => {
+                    let box_object = BoxObject {
+                        origin: Coordinates::new((x, y)),
+                        dimensions: (1, 1),
+                    };
+                    Point::Box(Rc::new(RefCell::new(box_object)))
+                }
+                '.' => Point::Empty,
+                '@' => {
+                    if robot_coords.is_some() {
+                        return Err(Error::at(y + 1, x + 1, "Only one robot is supported."));
+                    }
+                    robot_coords = Some(Coordinates::new((x, y)));
+                    Point::Robot
+                }
+                _ => {
+                    return Err(Error::at(
+                        y + 1,
+                        x + 1,
+                        format!("Invalid grid character '{char}'."),
+                    ))
+                }
+            };
+            row.push(point);
+        }
+
+        rows.push(row);
+    }
+
+    for (i, line) in line_iterator {
+        for (x, char) in line.chars().enumerate() {
+            let move_instruction = Direction::try_from(char).map_err(|_| {
+                Error::at(i + 1, x + 1, format!("Invalid move character '{char}'."))
+            })?;
+            move_instructions.push(move_instruction);
+        }
+    }
+
+    validate_walls(&rows)?;
+
+    let robot_coords = robot_coords.ok_or_else(|| Error::new("No robot found."))?;
+
+    tracing::debug!(rows = rows.len(), moves = move_instructions.len(), "parsed");
+
+    Ok((Grid::new(rows), move_instructions, robot_coords))
+}
+
+/// Movement relies on the grid being rectangular and surrounded by walls - nothing can move out of bounds.
+fn validate_walls(rows: &[Vec<Point>]) -> Result<()> {
+    let width = rows.first().map_or(0, |row| row.len());
+
+    for (y, row) in rows.iter().enumerate() {
+        if row.len() != width {
+            return Err(Error::at_line(
+                y + 1,
+                format!("Expected {width} grid characters, grid must be rectangular."),
+            ));
+        }
+
+        for (x, point) in row.iter().enumerate() {
+            let is_border = y == 0 || y == rows.len() - 1 || x == 0 || x == width - 1;
+
+            if is_border && *point != Point::Obstacle {
+                return Err(Error::at(
+                    y + 1,
+                    x + 1,
+                    "Grid must be surrounded by walls '#'.",
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn create_enlarged_grid(grid: &PointGrid) -> PointGrid {
+    let mut new_grid = Grid::new(vec![]);
+
+    for row in grid.get_rows().iter() {
+        let mut new_row = vec![];
+
+        for point in row.iter() {
+            match point {
+                Point::Empty => {
+                    new_row.push(Point::Empty);
+                    new_row.push(Point::Empty);
+                }
+                Point::Obstacle => {
+                    new_row.push(Point::Obstacle);
+                    new_row.push(Point::Obstacle);
+                }
+                Point::Box(box_object) => {
+                    let mut cloned_box_object = box_object.borrow().clone();
+                    cloned_box_object.origin.x *= 2;
+                    cloned_box_object.dimensions.0 *= 2;
+
+                    let rc = Rc::new(RefCell::new(cloned_box_object));
+                    new_row.push(Point::Box(Rc::clone(&rc)));
+                    new_row.push(Point::Box(Rc::clone(&rc)));
+                }
+                Point::Robot => {
+                    new_row.push(Point::Robot);
+                    new_row.push(Point::Empty);
+                }
+            }
+        }
+
+        new_grid.get_rows_mut().push(new_row);
+    }
+
+    new_grid
+}
+
+/// Grid in the puzzle notation (`[]` for wide boxes).
+fn render_grid(grid: &PointGrid) -> String {
+    let mut picture = String::new();
+
+    for (y, row) in grid.get_rows().iter().enumerate() {
+        for (x, point) in row.iter().enumerate() {
+            let character = match point {
+                Point::Empty => '.',
+                Point::Obstacle => '#',
+                Point::Box(box_ref) => match box_ref.borrow().dimensions.0 {
+                    1 => 'O',
+                    _ if box_ref.borrow().origin == Coordinates::new((x, y)) => '[',
+                    _ => ']',
+                },
+                Point::Robot => '@',
+            };
+            picture.push(character);
+        }
+        picture.push('\n');
+    }
+
+    picture
+}
+
+type PointGrid = Grid<Point>;
+type MovementInstructions = Vec<Direction>;
+type BoxRef = Rc<RefCell<BoxObject>>;
+
+#[derive(PartialEq, Clone, Debug)]
+struct BoxObject {
+    /// Top left corner
+    origin: Coordinates,
+    dimensions: (usize, usize),
+}
+
+impl BoxObject {
+    fn move_by(&mut self, distance: Distance) -> std::result::Result<(), ()> {
+        match self.origin.clone().safe_add_distance(distance) {
+            Some(new_origin) => {
+                self.origin = new_origin;
+                Ok(())
+            }
+            None => Err(()),
+        }
+    }
+
+    fn get_edge_coords(&self, direction: &Direction) -> Vec<Coordinates> {
+        let mut coords = vec![];
+
+        let variable_coord = match direction {
+            Direction::Up | Direction::Down => self.origin.x..(self.origin.x + self.dimensions.0),
+            Direction::Left | Direction::Right => {
+                self.origin.y..(self.origin.y + self.dimensions.1)
+            }
+        };
+
+        let constant_coord = match direction {
+            Direction::Up => self.origin.y,
+            Direction::Down => self.origin.y + self.dimensions.1 - 1,
+            Direction::Left => self.origin.x,
+            Direction::Right => self.origin.x + self.dimensions.0 - 1,
+        };
+
+        match direction {
+            Direction::Up | Direction::Down => {
+                for x in variable_coord {
+                    coords.push(Coordinates::new((x, constant_coord)));
+                }
+            }
+            Direction::Left | Direction::Right => {
+                for y in variable_coord {
+                    coords.push(Coordinates::new((constant_coord, y)));
+                }
+            }
+        }
+
+        coords
+    }
+
+    fn get_all_coords(&self) -> Vec<Coordinates> {
+        let mut coords = vec![];
+
+        for x in self.origin.x..(self.origin.x + self.dimensions.0) {
+            for y in self.origin.y..(self.origin.y + self.dimensions.1) {
+                coords.push(Coordinates::new((x, y)));
+            }
+        }
+
+        coords
+    }
+}
+
+#[derive(PartialEq)]
+enum Point {
+    Obstacle,
+    Box(BoxRef),
+    Empty,
+    Robot,
+}
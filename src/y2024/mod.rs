@@ -0,0 +1,151 @@
+//! Solutions for https://adventofcode.com/2024.
+//! Each day exposes its part one and part two answers as functions taking the puzzle input
+//! (and puzzle parameters for days whose examples use different values, see `crate::params`).
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+use crate::registry::Solution;
+
+const YEAR: u16 = 2024;
+
+pub const SOLUTIONS: [Solution; 15] = [
+    Solution {
+        year: YEAR,
+        day: 1,
+        parameters: &[],
+        part_one: |input, _| day01::total_distance(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day01::similarity_score(input).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 2,
+        parameters: &[],
+        part_one: |input, _| day02::safe_report_count(input).map(|answer| answer.to_string()),
+        part_two: |input, _| {
+            day02::safe_report_count_with_dampener(input).map(|answer| answer.to_string())
+        },
+    },
+    Solution {
+        year: YEAR,
+        day: 3,
+        parameters: &[],
+        part_one: |input, _| day03::multiplication_sum(input).map(|answer| answer.to_string()),
+        part_two: |input, _| {
+            day03::enabled_multiplication_sum(input).map(|answer| answer.to_string())
+        },
+    },
+    Solution {
+        year: YEAR,
+        day: 4,
+        parameters: &[],
+        part_one: |input, _| day04::xmas_count(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day04::x_mas_count(input).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 5,
+        parameters: &[],
+        part_one: |input, _| day05::valid_middle_sum(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day05::sorted_middle_sum(input).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 6,
+        parameters: &[],
+        part_one: |input, _| day06::visited_count(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day06::cycle_count(input).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 7,
+        parameters: &[],
+        part_one: |input, _| day07::calibration_result(input).map(|answer| answer.to_string()),
+        part_two: |input, _| {
+            day07::calibration_result_with_concatenation(input).map(|answer| answer.to_string())
+        },
+    },
+    Solution {
+        year: YEAR,
+        day: 8,
+        parameters: &[],
+        part_one: |input, _| day08::antinode_count(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day08::repeated_antinode_count(input).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 9,
+        parameters: &[],
+        part_one: |input, _| day09::checksum_by_block(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day09::checksum_by_file(input).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 10,
+        parameters: &day10::PARAMETERS,
+        part_one: |input, params| day10::score_sum(input, params).map(|answer| answer.to_string()),
+        part_two: |input, params| day10::rating_sum(input, params).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 11,
+        parameters: &day11::PARAMETERS,
+        part_one: |input, params| {
+            let blinks = params.get("part_one_blinks", day11::PART_ONE_BLINKS)?;
+            day11::stone_count(input, blinks).map(|answer| answer.to_string())
+        },
+        part_two: |input, params| {
+            let blinks = params.get("part_two_blinks", day11::PART_TWO_BLINKS)?;
+            day11::stone_count(input, blinks).map(|answer| answer.to_string())
+        },
+    },
+    Solution {
+        year: YEAR,
+        day: 12,
+        parameters: &[],
+        part_one: |input, _| day12::fence_price_perimeter(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day12::fence_price_sides(input).map(|answer| answer.to_string()),
+    },
+    Solution {
+        year: YEAR,
+        day: 13,
+        parameters: &day13::PARAMETERS,
+        part_one: |input, params| {
+            day13::token_price(input, params).map(|answer| answer.to_string())
+        },
+        part_two: |input, params| {
+            day13::token_price_increased_distance(input, params).map(|answer| answer.to_string())
+        },
+    },
+    Solution {
+        year: YEAR,
+        day: 14,
+        parameters: &day14::PARAMETERS,
+        part_one: |input, params| {
+            day14::safety_factor(input, params).map(|answer| answer.to_string())
+        },
+        part_two: |input, params| {
+            day14::easter_egg_iterations(input, params).map(|answer| answer.to_string())
+        },
+    },
+    Solution {
+        year: YEAR,
+        day: 15,
+        parameters: &[],
+        part_one: |input, _| day15::gps_sum(input).map(|answer| answer.to_string()),
+        part_two: |input, _| day15::gps_sum_enlarged(input).map(|answer| answer.to_string()),
+    },
+];
@@ -0,0 +1,406 @@
+use advent_of_code::{
+    animation::{animations, Recording},
+    generate::Rng,
+    image::{encode_gif, encode_png, Image, Rgb},
+    input::{input_path, read_input},
+    params::Params,
+};
+
+/// Every animation records the requested frames of the puzzle input and encodes them.
+#[test]
+fn animations_encode() {
+    let recording = Recording {
+        start: 0,
+        step: 5,
+        frames: 3,
+    };
+
+    for animation in animations() {
+        let input = read_input(&input_path(animation.year, animation.day)).unwrap();
+        let snapshots = (animation.record)(&input, &Params::default(), &recording).unwrap();
+
+        let iterations: Vec<usize> = snapshots
+            .iter()
+            .map(|snapshot| snapshot.iteration)
+            .collect();
+        assert_eq!(
+            iterations,
+            [0, 5, 10],
+            "{} day {}",
+            animation.year,
+            animation.day
+        );
+
+        let images: Vec<_> = snapshots
+            .iter()
+            .map(|snapshot| snapshot.to_image(2, animation.colour))
+            .collect();
+        let gif = encode_gif(&images, 10).unwrap();
+        let (palette, decoded) = decode_gif(&gif);
+        assert_eq!(decoded.len(), images.len());
+        for (image, decoded) in images.iter().zip(decoded) {
+            let pixels: Vec<Rgb> = decoded
+                .indexes
+                .iter()
+                .map(|&index| palette[index as usize])
+                .collect();
+            assert_eq!(pixels, image.pixels);
+        }
+    }
+}
+
+fn image(width: usize, height: usize, mut pixel: impl FnMut(usize, usize) -> Rgb) -> Image {
+    let pixels = (0..height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .map(|(x, y)| pixel(x, y))
+        .collect();
+
+    Image {
+        width,
+        height,
+        pixels,
+    }
+}
+
+/// Frames of a decoded GIF - size, delay and palette indexes.
+struct GifFrame {
+    width: usize,
+    height: usize,
+    delay: u16,
+    indexes: Vec<u8>,
+}
+
+/// Minimal GIF decoder - global palette and frames, independent of the encoder.
+fn decode_gif(gif: &[u8]) -> (Vec<Rgb>, Vec<GifFrame>) {
+    assert_eq!(&gif[..6], b"GIF89a");
+    let word = |at: usize| u16::from_le_bytes([gif[at], gif[at + 1]]);
+
+    let packed = gif[10];
+    assert_ne!(packed & 0x80, 0, "global palette expected");
+    let palette_size = 2 << (packed & 0x07);
+    let mut at = 13;
+    let palette: Vec<Rgb> = gif[at..at + 3 * palette_size]
+        .chunks(3)
+        .map(|colour| Rgb(colour[0], colour[1], colour[2]))
+        .collect();
+    at += 3 * palette_size;
+
+    // Concatenated data sub-blocks starting at `at`, position after the terminator
+    let sub_blocks = |mut at: usize| {
+        let mut data = vec![];
+        while gif[at] != 0 {
+            let length = gif[at] as usize;
+            data.extend(&gif[at + 1..at + 1 + length]);
+            at += 1 + length;
+        }
+        (data, at + 1)
+    };
+
+    let mut frames = vec![];
+    let mut delay = 0;
+    loop {
+        match gif[at] {
+            0x21 => {
+                let label = gif[at + 1];
+                let (data, next) = sub_blocks(at + 2);
+                if label == 0xF9 {
+                    delay = u16::from_le_bytes([data[1], data[2]]);
+                }
+                at = next;
+            }
+            0x2C => {
+                let (width, height) = (word(at + 5) as usize, word(at + 7) as usize);
+                assert_eq!(gif[at + 9] & 0x80, 0, "no local palette expected");
+                let min_code_size = gif[at + 10];
+                let (data, next) = sub_blocks(at + 11);
+                frames.push(GifFrame {
+                    width,
+                    height,
+                    delay,
+                    indexes: lzw_decode(&data, min_code_size),
+                });
+                at = next;
+            }
+            0x3B => {
+                assert_eq!(at, gif.len() - 1, "data after the trailer");
+                return (palette, frames);
+            }
+            block => panic!("Unexpected block 0x{block:02X} at {at}."),
+        }
+    }
+}
+
+/// GIF LZW decoder - codes LSB first, code size grows once the table is full for the current size.
+fn lzw_decode(data: &[u8], min_code_size: u8) -> Vec<u8> {
+    let clear_code = 1usize << min_code_size;
+    let end_code = clear_code + 1;
+    let initial_table = || -> Vec<Vec<u8>> {
+        (0..=end_code)
+            .map(|code| vec![code.min(255) as u8])
+            .collect()
+    };
+
+    let mut table = initial_table();
+    let mut code_size = min_code_size + 1;
+    let mut previous: Option<Vec<u8>> = None;
+    let mut output = vec![];
+    let mut bit = 0;
+
+    loop {
+        assert!(
+            bit + code_size as usize <= data.len() * 8,
+            "LZW data ended without the end code"
+        );
+        let code = (0..code_size as usize).fold(0, |code, i| {
+            let position = bit + i;
+            code | (((data[position / 8] >> (position % 8)) as usize & 1) << i)
+        });
+        bit += code_size as usize;
+
+        if code == clear_code {
+            table = initial_table();
+            code_size = min_code_size + 1;
+            previous = None;
+            continue;
+        }
+        if code == end_code {
+            break;
+        }
+
+        let entry = match (table.get(code), &previous) {
+            (Some(entry), _) => entry.clone(),
+            // Code being defined by this very step - previous + its first index
+            (None, Some(previous)) if code == table.len() => {
+                let mut entry = previous.clone();
+                entry.push(previous[0]);
+                entry
+            }
+            _ => panic!("Invalid LZW code {code} (table size {}).", table.len()),
+        };
+        output.extend(&entry);
+
+        if let Some(previous) = previous {
+            if table.len() < 4096 {
+                let mut new_entry = previous;
+                new_entry.push(entry[0]);
+                table.push(new_entry);
+            }
+        }
+        if table.len() == 1 << code_size && code_size < 12 {
+            code_size += 1;
+        }
+        previous = Some(entry);
+    }
+
+    // Padding of the last byte only
+    assert!(data.len() * 8 - bit < 8, "data after the end code");
+
+    output
+}
+
+#[test]
+fn gif_round_trip() {
+    let mut rng = Rng::new(37);
+    let mut random_colour = |count: i64| {
+        let value = rng.range(0..=count - 1) as u8;
+        Rgb(value, value.wrapping_mul(7), 255 - value)
+    };
+
+    let cases = [
+        // Single colour, single pixel
+        vec![image(1, 1, |_, _| Rgb(1, 2, 3))],
+        // Two colours - the smallest code size, long runs
+        vec![
+            image(40, 30, |x, y| {
+                if (x / 5 + y / 3) % 2 == 0 {
+                    Rgb(0, 0, 0)
+                } else {
+                    Rgb(255, 255, 255)
+                }
+            }),
+            image(40, 30, |x, _| {
+                if x < 20 {
+                    Rgb(0, 0, 0)
+                } else {
+                    Rgb(255, 255, 255)
+                }
+            }),
+        ],
+        // Odd palette size padded to a power of two
+        vec![image(7, 5, |x, y| {
+            [Rgb(1, 0, 0), Rgb(0, 1, 0), Rgb(0, 0, 1)][(x * y) % 3]
+        })],
+        // Noise with all 256 colours - fills the 4096 code table several times
+        vec![
+            image(160, 120, |_, _| random_colour(256)),
+            image(160, 120, |_, _| random_colour(16)),
+        ],
+    ];
+
+    for frames in cases {
+        let gif = encode_gif(&frames, 7).unwrap();
+        let (palette, decoded) = decode_gif(&gif);
+
+        assert!(palette.len().is_power_of_two() && palette.len() <= 256);
+        assert_eq!(decoded.len(), frames.len());
+        for (frame, decoded) in frames.iter().zip(&decoded) {
+            assert_eq!((decoded.width, decoded.height), (frame.width, frame.height));
+            assert_eq!(decoded.delay, 7);
+
+            let pixels: Vec<Rgb> = decoded
+                .indexes
+                .iter()
+                .map(|&index| palette[index as usize])
+                .collect();
+            assert_eq!(pixels, frame.pixels);
+        }
+    }
+}
+
+#[test]
+fn gif_errors() {
+    assert!(encode_gif(&[], 10).is_err());
+    let frames = [
+        image(2, 2, |_, _| Rgb(0, 0, 0)),
+        image(2, 3, |_, _| Rgb(0, 0, 0)),
+    ];
+    assert!(encode_gif(&frames, 10).is_err());
+    let colours = image(300, 1, |x, _| Rgb(x as u8, (x / 256) as u8, 0));
+    assert_eq!(
+        encode_gif(&[colours], 10).err().unwrap().to_string(),
+        "GIF supports at most 256 colours."
+    );
+}
+
+/// CRC-32 (ISO-HDLC, as in PNG), table driven.
+fn crc32(data: &[u8]) -> u32 {
+    let table: Vec<u32> = (0..256u32)
+        .map(|byte| {
+            (0..8).fold(byte, |crc, _| {
+                if crc & 1 == 1 {
+                    (crc >> 1) ^ 0xEDB88320
+                } else {
+                    crc >> 1
+                }
+            })
+        })
+        .collect();
+
+    !data.iter().fold(u32::MAX, |crc, &byte| {
+        table[((crc ^ u32::from(byte)) & 0xFF) as usize] ^ (crc >> 8)
+    })
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (a, b) = data.iter().fold((1u64, 0u64), |(a, b), &byte| {
+        let a = (a + u64::from(byte)) % 65521;
+        (a, (b + a) % 65521)
+    });
+
+    ((b << 16) | a) as u32
+}
+
+#[test]
+fn checksums() {
+    assert_eq!(crc32(b"123456789"), 0xCBF43926);
+    assert_eq!(crc32(b"IEND"), 0xAE426082);
+    assert_eq!(adler32(b"Wikipedia"), 0x11E60398);
+    assert_eq!(adler32(b""), 1);
+}
+
+/// Chunks of a PNG file with verified CRCs - type and data.
+fn png_chunks(png: &[u8]) -> Vec<([u8; 4], Vec<u8>)> {
+    assert_eq!(&png[..8], &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]);
+
+    let mut chunks = vec![];
+    let mut at = 8;
+    while at < png.len() {
+        let length = u32::from_be_bytes(png[at..at + 4].try_into().unwrap()) as usize;
+        let type_and_data = &png[at + 4..at + 8 + length];
+        let crc = u32::from_be_bytes(png[at + 8 + length..at + 12 + length].try_into().unwrap());
+        assert_eq!(crc, crc32(type_and_data), "CRC of chunk at {at}");
+
+        chunks.push((
+            type_and_data[..4].try_into().unwrap(),
+            type_and_data[4..].to_vec(),
+        ));
+        at += 12 + length;
+    }
+    assert_eq!(at, png.len());
+
+    chunks
+}
+
+/// Data of a zlib stream made of stored deflate blocks, with verified block lengths and Adler-32.
+fn inflate_stored(zlib: &[u8]) -> (Vec<u8>, usize) {
+    assert_eq!(zlib[0] & 0x0F, 8, "deflate compression method");
+    assert_eq!(
+        u16::from_be_bytes([zlib[0], zlib[1]]) % 31,
+        0,
+        "zlib header check bits"
+    );
+    assert_eq!(zlib[1] & 0x20, 0, "no preset dictionary");
+
+    let mut data = vec![];
+    let mut block_count = 0;
+    let mut at = 2;
+    loop {
+        let header = zlib[at];
+        assert_eq!(header & 0b110, 0, "stored block type");
+        let length = u16::from_le_bytes([zlib[at + 1], zlib[at + 2]]);
+        let inverted = u16::from_le_bytes([zlib[at + 3], zlib[at + 4]]);
+        assert_eq!(length, !inverted, "NLEN is the complement of LEN");
+
+        data.extend(&zlib[at + 5..at + 5 + length as usize]);
+        at += 5 + length as usize;
+        block_count += 1;
+        if header & 1 == 1 {
+            break;
+        }
+    }
+
+    assert_eq!(at + 4, zlib.len(), "Adler-32 ends the stream");
+    assert_eq!(zlib[at..].to_vec(), adler32(&data).to_be_bytes());
+
+    (data, block_count)
+}
+
+#[test]
+fn png_structure() {
+    // (width, height, stored block count) - 200 x 120 RGB scanlines don't fit a single 65535 byte block
+    for (width, height, expected_blocks) in [(1, 1, 1), (5, 3, 1), (0, 0, 1), (200, 120, 2)] {
+        let frame = image(width, height, |x, y| Rgb(x as u8, y as u8, (x * y) as u8));
+        let chunks = png_chunks(&encode_png(&frame));
+
+        let types: Vec<&[u8; 4]> = chunks.iter().map(|(chunk_type, _)| chunk_type).collect();
+        assert_eq!(types, [b"IHDR", b"IDAT", b"IEND"]);
+
+        let header = &chunks[0].1;
+        assert_eq!(header.len(), 13);
+        assert_eq!(
+            u32::from_be_bytes(header[0..4].try_into().unwrap()),
+            width as u32
+        );
+        assert_eq!(
+            u32::from_be_bytes(header[4..8].try_into().unwrap()),
+            height as u32
+        );
+        // Bit depth 8, RGB, deflate, adaptive filtering, no interlace
+        assert_eq!(header[8..], [8, 2, 0, 0, 0]);
+
+        let (raw, block_count) = inflate_stored(&chunks[1].1);
+        assert_eq!(block_count, expected_blocks, "{width}x{height}");
+
+        let expected: Vec<u8> = frame
+            .pixels
+            .chunks(width.max(1))
+            .take(height)
+            .flat_map(|row| {
+                std::iter::once(0).chain(row.iter().flat_map(|pixel| [pixel.0, pixel.1, pixel.2]))
+            })
+            .collect();
+        assert_eq!(raw, expected);
+
+        assert!(chunks[2].1.is_empty());
+    }
+}
@@ -0,0 +1,150 @@
+use std::{collections::HashMap, env, fs};
+
+use advent_of_code::config::{Config, OutputFormat, CONFIG_ENV};
+
+const CONFIG: &str = r#"
+# Comment line
+default_year = 2024
+input_dir = "./inputs # not a comment"  # comment
+input_file = "day_{day}.txt"
+threads = 1_0
+output_format = "tsv"
+
+[params.2024.14]
+x_len = 11
+y_len = 7
+
+[ params.2024.6 ]
+label = "a=b"
+"#;
+
+#[test]
+fn toml_subset() {
+    let config = Config::parse(CONFIG).unwrap();
+
+    assert_eq!(config.default_year, Some(2024));
+    assert_eq!(config.input_dir, "./inputs # not a comment");
+    assert_eq!(config.threads, 10);
+    assert_eq!(config.output_format, OutputFormat::Tsv);
+    assert_eq!(
+        config.input_path(2024, 9),
+        "./inputs # not a comment/day_09.txt"
+    );
+    assert_eq!(
+        config.input_day_dir(2024, 9),
+        "./inputs # not a comment/2024/09"
+    );
+    // Untouched settings keep their defaults
+    assert_eq!(config.answers_path, Config::default().answers_path);
+    assert_eq!(config.session_token_path, None);
+
+    assert_eq!(Config::parse("").unwrap(), Config::default());
+}
+
+#[test]
+fn params_tables() {
+    let config = Config::parse(CONFIG).unwrap();
+
+    let params = config.params(2024, 14);
+    assert_eq!(params.get("x_len", 101), Ok(11));
+    assert_eq!(params.get("y_len", 103), Ok(7));
+    assert_eq!(params.get("iter_count", 100), Ok(100));
+    assert_eq!(
+        config.params(2024, 6).get("label", String::new()),
+        Ok("a=b".to_string())
+    );
+    assert_eq!(config.params(2024, 15), Default::default());
+
+    // Table keys don't leak to the top level
+    assert_eq!(config.params.len(), 2);
+    assert!(Config::parse("[params.2024.14]\nthreads = 0\n").is_ok());
+}
+
+#[test]
+fn parse_errors() {
+    let error = |content: &str| Config::parse(content).unwrap_err().to_string();
+
+    assert_eq!(error("threads"), "1: Expected `key = value`.");
+    assert_eq!(
+        error("\ninput_dir = ./inputs"),
+        "2:13: Expected string (in double quotes) or integer value."
+    );
+    assert_eq!(
+        error("input_dir = \"a\"b\""),
+        "1:13: Expected string (in double quotes) or integer value."
+    );
+    assert_eq!(
+        error("threads = 0"),
+        "1:1: Invalid value '0' of 'threads', number expected."
+    );
+    assert_eq!(
+        error("  output_format = \"json\""),
+        "1:3: Invalid output format 'json', expected `table` or `tsv`."
+    );
+    assert!(error("cache-dir = \"./.cache\"")
+        .starts_with("1:1: Unknown setting 'cache-dir', expected one of:"));
+    for header in [
+        "[params]",
+        "[params.2024]",
+        "[params.x.14]",
+        "[other.2024.14]",
+        "[params.2024.14",
+    ] {
+        assert_eq!(
+            error(&format!("threads = 2\n{header}")),
+            "2: Expected `[params.<year>.<day>]` table."
+        );
+    }
+}
+
+#[test]
+fn precedence() {
+    let path = env::temp_dir().join(format!("aoc_config_{}.toml", std::process::id()));
+    let path = path.to_str().unwrap();
+    fs::write(
+        path,
+        "threads = 2\ninput_dir = \"./file\"\nstats_path = \"./file.txt\"\ncache_dir = \"./file_cache\"\n",
+    )
+    .unwrap();
+
+    // File only
+    let config = Config::load(Some(path)).unwrap();
+    assert_eq!((config.threads, config.input_dir.as_str()), (2, "./file"));
+    assert_eq!(config.cache_dir, "./file_cache");
+
+    // Environment overrides the file, also when the file comes from `AOC_CONFIG`
+    env::set_var(CONFIG_ENV, path);
+    env::set_var("AOC_THREADS", "3");
+    env::set_var("AOC_INPUT_DIR", "./env");
+    env::set_var("AOC_CACHE_DIR", "./env_cache");
+    let mut config = Config::load(None).unwrap();
+    assert_eq!((config.threads, config.input_dir.as_str()), (3, "./env"));
+    assert_eq!(config.cache_dir, "./env_cache");
+    assert_eq!(config.stats_path, "./file.txt");
+
+    // Command line overrides both
+    let options = HashMap::from([
+        ("threads".to_string(), "4".to_string()),
+        ("output-format".to_string(), "tsv".to_string()),
+        ("cache-dir".to_string(), "./cli_cache".to_string()),
+        ("unrelated".to_string(), "x".to_string()),
+    ]);
+    config.apply_options(&options).unwrap();
+    assert_eq!((config.threads, config.input_dir.as_str()), (4, "./env"));
+    assert_eq!(config.output_format, OutputFormat::Tsv);
+    assert_eq!(config.cache_dir, "./cli_cache");
+
+    env::set_var("AOC_THREADS", "many");
+    assert_eq!(
+        Config::load(None).unwrap_err().to_string(),
+        "AOC_THREADS: Invalid value 'many' of 'threads', number expected."
+    );
+
+    for variable in [CONFIG_ENV, "AOC_THREADS", "AOC_INPUT_DIR", "AOC_CACHE_DIR"] {
+        env::remove_var(variable);
+    }
+    fs::remove_file(path).unwrap();
+
+    let err = Config::load(Some(path)).unwrap_err();
+    assert_eq!(err.file.as_deref(), Some(path));
+}
@@ -0,0 +1,82 @@
+use advent_of_code::cycle::{find_cycle_brent, find_cycle_floyd, find_cycle_hashed, Cycle};
+
+/// Cycle found by each variant - all must agree.
+fn find_cycle(initial: u64, step: impl Fn(&u64) -> Option<u64>) -> Option<Cycle> {
+    let hashed = find_cycle_hashed(initial, &step);
+    assert_eq!(find_cycle_floyd(initial, &step), hashed);
+    assert_eq!(find_cycle_brent(initial, &step), hashed);
+
+    hashed
+}
+
+/// Naive reference - index of the first repeated state and its first occurrence.
+fn naive_cycle(initial: u64, step: impl Fn(&u64) -> u64) -> Cycle {
+    let mut states = vec![initial];
+    loop {
+        let next = step(states.last().unwrap());
+        if let Some(start) = states.iter().position(|&state| state == next) {
+            return Cycle {
+                start,
+                length: states.len() - start,
+            };
+        }
+        states.push(next);
+    }
+}
+
+#[test]
+fn known_cycles() {
+    // 0 -> 1 -> 2 -> 3 -> 4 -> 2
+    let step = |&x: &u64| Some(if x == 4 { 2 } else { x + 1 });
+    assert_eq!(
+        find_cycle(0, step),
+        Some(Cycle {
+            start: 2,
+            length: 3
+        })
+    );
+    assert_eq!(
+        find_cycle(3, step),
+        Some(Cycle {
+            start: 0,
+            length: 3
+        })
+    );
+
+    // Fixed point
+    assert_eq!(
+        find_cycle(7, |&x| Some(x)),
+        Some(Cycle {
+            start: 0,
+            length: 1
+        })
+    );
+}
+
+#[test]
+fn pseudo_random_sequences() {
+    for modulus in [2, 17, 255, 1000, 65_537] {
+        let step = |x: &u64| (x * x + 1) % modulus;
+        for initial in [0, 1, 5, 42] {
+            let expected = naive_cycle(initial, step);
+            assert_eq!(
+                find_cycle(initial, |x| Some(step(x))),
+                Some(expected),
+                "x -> (x * x + 1) % {modulus} from {initial}"
+            );
+        }
+    }
+}
+
+#[test]
+fn halting_machines() {
+    assert_eq!(find_cycle(0, |_| None), None);
+    assert_eq!(find_cycle(0, |&x| (x < 100).then_some(x + 1)), None);
+    // Collatz sequence ends at 1
+    let collatz = |&x: &u64| match x {
+        1 => None,
+        _ if x % 2 == 0 => Some(x / 2),
+        _ => Some(3 * x + 1),
+    };
+    assert_eq!(find_cycle(27, collatz), None);
+}
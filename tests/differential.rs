@@ -0,0 +1,26 @@
+use advent_of_code::{differential, generate};
+
+const SEEDS: [u64; 20] = [
+    0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19,
+];
+
+#[test]
+fn solutions_match_references() {
+    let mut mismatches = vec![];
+
+    for reference in differential::references() {
+        let generator = generate::find(reference.year, reference.day)
+            .expect("Every reference should have a generator.");
+
+        if let Some(mismatch) =
+            differential::find_mismatch(reference, generator, &[1, 10, 50], &SEEDS)
+        {
+            mismatches.push(format!(
+                "{} day {} {}: {mismatch}",
+                reference.year, reference.day, reference.part
+            ));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{}", mismatches.join("\n\n"));
+}
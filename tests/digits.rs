@@ -0,0 +1,63 @@
+use advent_of_code::digits::Digits;
+
+#[test]
+fn match_string_operations() {
+    for value in (0u64..2000).chain([9_999_999, 10_000_000, 1_234_567_890, u64::MAX]) {
+        let string = value.to_string();
+        assert_eq!(value.digit_count() as usize, string.len());
+
+        let digits: Vec<u8> = value.digits().collect();
+        let expected: Vec<u8> = string.bytes().map(|byte| byte - b'0').collect();
+        assert_eq!(digits, expected);
+        assert_eq!(value.digits().len(), string.len());
+
+        for index in 0..=string.len() {
+            let (left, right) = string.split_at(index);
+            let expected = (left.parse().unwrap_or(0), right.parse().unwrap_or(0));
+            assert_eq!(
+                value.split_at_digit(index as u32),
+                expected,
+                "{value} at {index}"
+            );
+        }
+        assert_eq!(value.checked_split_at_digit(string.len() as u32 + 1), None);
+
+        let reversed: String = string.chars().rev().collect();
+        assert_eq!(value.checked_reverse_digits(), reversed.parse().ok());
+    }
+}
+
+#[test]
+fn concatenation() {
+    assert_eq!(12u32.concat(345), 12345);
+    assert_eq!(12u32.concat(0), 120);
+    assert_eq!(0u32.concat(7), 7);
+    assert_eq!(10usize.concat(10), 1010);
+
+    assert_eq!(25u8.concat(5), 255);
+    assert_eq!(25u8.checked_concat(6), None);
+    assert_eq!(1u8.checked_concat(100), None);
+    assert_eq!(1u128.checked_concat(u128::MAX), None);
+}
+
+#[test]
+fn small_types_dont_overflow() {
+    for value in 0..=u8::MAX {
+        assert_eq!(value.split_at_digit(0), (0, value));
+        assert_eq!(value.split_at_digit(value.digit_count()), (value, 0));
+        let reversed: String = value.to_string().chars().rev().collect();
+        assert_eq!(value.checked_reverse_digits(), reversed.parse().ok());
+    }
+
+    assert_eq!(u128::MAX.digits().count(), 39);
+    assert_eq!(
+        u128::MAX.split_at_digit(1),
+        (3, u128::MAX - 3 * 10u128.pow(38))
+    );
+}
+
+#[test]
+#[should_panic(expected = "Digit concatenation overflowed.")]
+fn overflow_panics() {
+    u16::MAX.concat(1);
+}
@@ -0,0 +1,46 @@
+use std::collections::HashSet;
+
+use advent_of_code::{Direction, DIRECTIONS};
+
+#[test]
+fn rotation_and_inversion() {
+    for direction in DIRECTIONS {
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+        assert_eq!(direction.opposite().opposite(), direction);
+
+        let (x, y) = direction.step_distance();
+        assert_eq!(direction.opposite().step_distance(), (-x, -y));
+        // Screen coordinates - y grows down
+        assert_eq!(direction.turn_right().step_distance(), (-y, x));
+    }
+}
+
+#[test]
+fn chars_and_indexes() {
+    for (chars, direction) in [
+        ("^UN", Direction::Up),
+        ("vDS", Direction::Down),
+        ("<LW", Direction::Left),
+        (">RE", Direction::Right),
+    ] {
+        for char in chars.chars() {
+            assert_eq!(Direction::try_from(char), Ok(direction));
+        }
+        assert_eq!(char::from(direction), chars.chars().next().unwrap());
+    }
+    assert_eq!(
+        Direction::try_from('x').unwrap_err().to_string(),
+        "Invalid direction 'x'."
+    );
+
+    let indexes: HashSet<usize> = DIRECTIONS
+        .iter()
+        .map(|direction| direction.index())
+        .collect();
+    assert_eq!(indexes, HashSet::from([0, 1, 2, 3]));
+    assert_eq!(
+        Direction::Up.turn_right().index(),
+        Direction::Up.index() + 1
+    );
+}
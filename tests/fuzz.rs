@@ -0,0 +1,34 @@
+use advent_of_code::fuzz::{find, targets, Run};
+
+/// Short run of every target, longer runs by `aoc fuzz <year> <day>`.
+#[test]
+fn targets_never_panic() {
+    for target in targets() {
+        let corpus = target.seed_corpus();
+        // Solvers are much slower than the parsers
+        let iterations = match target.run {
+            Run::Function(_) => 500,
+            Run::Solve => 20,
+        };
+
+        if let Some(crash) = target.fuzz(&corpus, iterations, 0) {
+            panic!(
+                "{} day {} {} panicked: {}\nMinimised input:\n{}",
+                target.year, target.day, target.name, crash.message, crash.input
+            );
+        }
+    }
+}
+
+#[test]
+fn every_day_has_targets() {
+    for day in 1..=15 {
+        let parser = find(2024, day, None).unwrap();
+        assert_ne!(parser.name, "solve", "day {day} has no parser target");
+        assert!(find(2024, day, Some("solve")).is_some());
+    }
+
+    assert_eq!(find(2024, 3, None).unwrap().name, "expressions");
+    assert!(find(2024, 3, Some("parse_file")).is_none());
+    assert!(find(2024, 16, None).is_none());
+}
@@ -0,0 +1,50 @@
+use advent_of_code::{
+    generate::{self, Generator},
+    params::Params,
+    registry::{self, Outcome, PARTS},
+};
+
+const SEEDS: [u64; 3] = [0, 1, 2];
+
+/// Small sizes to keep the tests fast, including degenerate ones.
+fn test_sizes(generator: &Generator) -> Vec<usize> {
+    match generator.day {
+        // Grid side / map length
+        4 | 6 | 8 | 10 | 12 | 15 => vec![0, 1, 5, 12],
+        9 => vec![1, 2, 15, 16],
+        // Easter egg search is slow (up to 101 * 103 iterations)
+        14 => vec![10],
+        _ => vec![0, 1, 10],
+    }
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    for generator in generate::generators() {
+        let solution = registry::find(generator.year, generator.day).unwrap();
+
+        for size in test_sizes(generator) {
+            for seed in SEEDS {
+                let input = generator.generate(size, seed);
+
+                for part in PARTS {
+                    let outcome = solution.solve(part, &input, &Params::default());
+                    assert!(
+                        matches!(outcome, Outcome::Answer(_)),
+                        "{}/{:02} {part} size {size} seed {seed}: {outcome}\n{input}",
+                        generator.year,
+                        generator.day,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn generators_are_deterministic() {
+    for generator in generate::generators() {
+        assert_eq!(generator.generate(10, 42), generator.generate(10, 42));
+        assert_ne!(generator.generate(10, 1), generator.generate(10, 2));
+    }
+}
@@ -0,0 +1,83 @@
+use advent_of_code::{graph::DiGraph, y2024::day05};
+
+#[test]
+fn topological_sort() {
+    let graph: DiGraph<&str> = [
+        ("shirt", "tie"),
+        ("tie", "jacket"),
+        ("trousers", "shoes"),
+        ("trousers", "belt"),
+        ("belt", "jacket"),
+        ("shirt", "belt"),
+        ("socks", "shoes"),
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(graph.node_count(), 7);
+    assert_eq!(graph.edge_count(), 7);
+
+    let sorted = graph.topological_sort().unwrap();
+    assert_eq!(sorted.len(), 7);
+    for from in graph.nodes() {
+        for to in graph.successors(from) {
+            let position = |node| sorted.iter().position(|&sorted| sorted == node);
+            assert!(position(from) < position(to), "{from} before {to}");
+        }
+    }
+    assert_eq!(graph.find_cycle(), None);
+
+    // Ties in insertion order
+    assert_eq!(
+        sorted,
+        ["shirt", "trousers", "socks", "tie", "belt", "shoes", "jacket"]
+    );
+}
+
+#[test]
+fn cycles() {
+    let mut graph: DiGraph<u32> = [(1, 2), (2, 3), (3, 4), (4, 2), (4, 5)]
+        .into_iter()
+        .collect();
+    assert_eq!(graph.topological_sort(), None);
+    assert_eq!(graph.find_cycle(), Some(vec![2, 3, 4]));
+
+    // Induced subgraph without the cycle's node
+    let subgraph = graph.induced_subgraph([5, 4, 3, 1, 6]);
+    assert_eq!(subgraph.nodes().collect::<Vec<_>>(), vec![5, 4, 3, 1, 6]);
+    assert!(subgraph.has_edge(4, 5) && subgraph.has_edge(3, 4));
+    assert!(!subgraph.has_edge(4, 2));
+    assert_eq!(subgraph.topological_sort(), Some(vec![3, 1, 6, 4, 5]));
+
+    graph.add_edge(7, 7);
+    assert_eq!(graph.induced_subgraph([7]).find_cycle(), Some(vec![7]));
+}
+
+#[test]
+fn reachability() {
+    let mut graph: DiGraph<char> = [('a', 'b'), ('b', 'c'), ('d', 'c'), ('c', 'e')]
+        .into_iter()
+        .collect();
+    graph.add_node('f');
+    graph.add_edge('a', 'b');
+    assert_eq!(graph.edge_count(), 4);
+
+    assert_eq!(graph.reachable('a'), vec!['a', 'b', 'c', 'e']);
+    assert_eq!(graph.reachable('f'), vec!['f']);
+    assert_eq!(graph.reachable('x'), vec![]);
+    assert!(graph.is_reachable('d', 'e'));
+    assert!(graph.is_reachable('f', 'f'));
+    assert!(!graph.is_reachable('e', 'a'));
+    assert!(!graph.is_reachable('a', 'x'));
+}
+
+#[test]
+fn day05_reports_rule_cycle() {
+    let input = "1|2\n2|3\n3|1\n\n3,2,1\n";
+    let err = day05::sorted_middle_sum(input).unwrap_err();
+    assert_eq!(err.line, Some(5));
+    assert!(
+        err.description
+            .ends_with("rules are cyclic: 3 -> 1 -> 2 -> 3."),
+        "{err}"
+    );
+}
@@ -0,0 +1,84 @@
+use advent_of_code::{
+    grid_n::{GridN, PointN, VectorN},
+    Coordinates, Grid,
+};
+
+#[test]
+fn vectors() {
+    assert_eq!(VectorN::<3>::orthogonal_units().count(), 6);
+    assert_eq!(VectorN::<3>::all_units().count(), 26);
+    assert_eq!(VectorN::<4>::all_units().count(), 80);
+    assert!(VectorN::<2>::all_units().all(|vector| vector != VectorN::ZERO));
+
+    let vector = VectorN([1, -2, 3]);
+    assert_eq!(vector + VectorN([1, 1, 1]), VectorN([2, -1, 4]));
+    assert_eq!(vector - vector, VectorN::ZERO);
+    assert_eq!(-vector * 2, VectorN([-2, 4, -6]));
+    assert_eq!(vector.manhattan_length(), 6);
+
+    let point = PointN([1, 2, 3]);
+    assert_eq!(point.checked_add(vector), Some(PointN([2, 0, 6])));
+    assert_eq!(point.checked_add(VectorN([-2, 0, 0])), None);
+    assert_eq!(point.vector_to(&PointN([0, 0, 0])), VectorN([-1, -2, -3]));
+    assert_eq!(point.manhattan_distance(&PointN([4, 2, 0])), 6);
+}
+
+#[test]
+fn dense_storage() {
+    let mut cube = GridN::from_fn([2, 3, 4], |PointN([x, y, z])| x + 10 * y + 100 * z);
+    assert_eq!(cube.len(), 24);
+    assert_eq!(cube[PointN([1, 2, 3])], 321);
+    assert_eq!(cube.get(PointN([2, 0, 0])), None);
+
+    cube[PointN([0, 1, 2])] = 0;
+    *cube.get_mut(PointN([1, 1, 1])).unwrap() += 1000;
+    assert_eq!(cube[PointN([0, 1, 2])], 0);
+    assert_eq!(cube[PointN([1, 1, 1])], 1111);
+
+    // Axis 0 varies fastest
+    let points: Vec<PointN<3>> = cube.points().take(3).collect();
+    assert_eq!(
+        points,
+        [PointN([0, 0, 0]), PointN([1, 0, 0]), PointN([0, 1, 0])]
+    );
+    assert!(cube.iter().all(|(point, value)| cube[point] == *value));
+    assert_eq!(cube.points().count(), cube.len());
+
+    let empty: GridN<u8, 2> = GridN::new([0, 5], 0);
+    assert!(empty.is_empty());
+    assert_eq!(empty.points().count(), 0);
+}
+
+#[test]
+fn neighbours_within_bounds() {
+    let grid = GridN::new([3, 3, 3], '.');
+    let neighbours = |point| grid.neighbours(PointN(point)).count();
+    let all_neighbours = |point| grid.all_neighbours(PointN(point)).count();
+
+    assert_eq!((neighbours([1, 1, 1]), all_neighbours([1, 1, 1])), (6, 26));
+    assert_eq!((neighbours([0, 0, 0]), all_neighbours([0, 0, 0])), (3, 7));
+    assert_eq!((neighbours([2, 1, 0]), all_neighbours([2, 1, 0])), (4, 11));
+    assert!(grid
+        .all_neighbours(PointN([0, 2, 1]))
+        .all(|neighbour| neighbour.0.iter().all(|&coordinate| coordinate < 3)));
+
+    let line = GridN::new([5], 0u8);
+    let neighbours: Vec<PointN<1>> = line.neighbours(PointN([4])).collect();
+    assert_eq!(neighbours, [PointN([3])]);
+}
+
+#[test]
+fn from_2d_grid() {
+    let grid = Grid::new(vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+    let grid_n = GridN::try_from(&grid).unwrap();
+    assert_eq!(grid_n.dims(), [3, 2]);
+
+    for point in grid_n.points() {
+        let coords = Coordinates::from(point);
+        assert_eq!(grid.at(&coords), Some(&grid_n[point]));
+        assert_eq!(PointN::from(coords), point);
+    }
+
+    let err = GridN::from_rows(vec![vec![1, 2], vec![3]]).unwrap_err();
+    assert_eq!(err.line, Some(2));
+}
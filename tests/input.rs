@@ -0,0 +1,95 @@
+use std::{env, fs};
+
+use advent_of_code::{
+    input::{normalize, read_input},
+    y2024::{day05, day09, day11},
+};
+
+const DAY05_EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+#[test]
+fn normalized_text() {
+    assert_eq!(normalize("\u{FEFF}1 2\n"), "1 2");
+    assert_eq!(normalize("a\r\nb\r\n\r\nc\r\n"), "a\nb\n\nc");
+    assert_eq!(normalize("a\rb\r"), "a\nb");
+    assert_eq!(normalize("a\n\n \n\t"), "a");
+    // Only a leading byte order mark is stripped, leading whitespace is kept
+    assert_eq!(normalize(" a\u{FEFF}"), " a\u{FEFF}");
+    assert_eq!(normalize(""), "");
+}
+
+#[test]
+fn byte_order_mark() {
+    let input = normalize("\u{FEFF}125 17");
+    assert_eq!(day11::stone_count(&input, 25), Ok(55312));
+
+    let input = normalize(&format!("\u{FEFF}{DAY05_EXAMPLE}"));
+    assert_eq!(day05::valid_middle_sum(&input), Ok(143));
+}
+
+#[test]
+fn crlf_line_endings() {
+    let input = normalize(&DAY05_EXAMPLE.replace('\n', "\r\n"));
+    assert_eq!(input, normalize(DAY05_EXAMPLE));
+    assert_eq!(day05::valid_middle_sum(&input), Ok(143));
+    assert_eq!(day05::sorted_middle_sum(&input), Ok(123));
+}
+
+#[test]
+fn trailing_newlines() {
+    for suffix in ["", "\n", "\r\n", "\n\n"] {
+        let input = normalize(&format!("2333133121414131402{suffix}"));
+        assert_eq!(day09::checksum_by_block(&input), Ok(1928));
+        assert_eq!(day09::checksum_by_file(&input), Ok(2858));
+
+        let input = normalize(&format!("125 17{suffix}"));
+        assert_eq!(day11::stone_count(&input, 6), Ok(22));
+        assert_eq!(day11::stone_count(&input, 25), Ok(55312));
+    }
+}
+
+#[test]
+fn read_input_normalizes_file() {
+    let path = env::temp_dir().join(format!("aoc_input_{}.txt", std::process::id()));
+    fs::write(
+        &path,
+        format!("\u{FEFF}{}", DAY05_EXAMPLE.replace('\n', "\r\n")),
+    )
+    .unwrap();
+
+    let input = read_input(path.to_str().unwrap());
+    fs::remove_file(&path).unwrap();
+    assert_eq!(input, Ok(normalize(DAY05_EXAMPLE)));
+
+    let err = read_input("./resources/missing.txt").unwrap_err();
+    assert_eq!(err.file.as_deref(), Some("./resources/missing.txt"));
+}
@@ -0,0 +1,57 @@
+use std::collections::BTreeMap;
+
+use advent_of_code::json::{Value, MAX_DEPTH};
+
+#[test]
+fn parse_values() {
+    let value =
+        Value::parse(r#" {"a": [1, -2.5e1, true, null], "b": "x\"é🎄\n", "c": {}} "#).unwrap();
+
+    assert_eq!(
+        value.get("a"),
+        Some(&Value::Array(vec![
+            Value::Number(1.0),
+            Value::Number(-25.0),
+            Value::Bool(true),
+            Value::Null,
+        ]))
+    );
+    assert_eq!(value.get("b").and_then(Value::as_str), Some("x\"é🎄\n"));
+    assert_eq!(value.get("c"), Some(&Value::Object(BTreeMap::new())));
+    assert_eq!(
+        Value::parse("1733029200").unwrap().as_u64(),
+        Some(1_733_029_200)
+    );
+    assert_eq!(Value::parse("1.5").unwrap().as_u64(), None);
+}
+
+#[test]
+fn errors_point_to_position() {
+    let error = |text: &str| Value::parse(text).err().unwrap().to_string();
+
+    assert_eq!(error("{\n  \"a\": 01\n}"), "2:8: Invalid number.");
+    assert_eq!(error("[1, 2"), "1:6: Expected ',' or ']'.");
+    assert_eq!(error("{\"a\" 1}"), "1:6: Expected ':'.");
+    assert_eq!(error("\"abc"), "1:5: Unterminated string.");
+    assert_eq!(error("[] x"), "1:4: Unexpected content after the value.");
+    assert_eq!(error("tru"), "1:1: Expected 'true'.");
+}
+
+#[test]
+fn nesting_depth() {
+    let nested = |depth: usize| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+
+    assert!(Value::parse(&nested(MAX_DEPTH)).is_ok());
+    assert_eq!(
+        Value::parse(&nested(MAX_DEPTH + 1))
+            .err()
+            .unwrap()
+            .to_string(),
+        format!(
+            "1:{}: Nested deeper than {MAX_DEPTH} levels.",
+            MAX_DEPTH + 1
+        )
+    );
+    // Would overflow the stack without the limit
+    assert!(Value::parse(&"{\"a\":".repeat(1_000_000)).is_err());
+}
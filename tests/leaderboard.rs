@@ -0,0 +1,261 @@
+use std::{
+    env, fs,
+    io::{Read, Write},
+    net::TcpListener,
+    thread,
+};
+
+use advent_of_code::leaderboard::{unlock_time, Leaderboard};
+
+/// 2024-12-01 00:00 UTC-5
+const UNLOCK: u64 = 1_733_029_200;
+
+const EXPORT: &str = r#"{
+  "event": "2024",
+  "owner_id": 1,
+  "members": {
+    "1": {"id": 1, "name": "Alice", "local_score": 10, "global_score": 0, "stars": 3, "last_star_ts": 1733119200,
+          "completion_day_level": {
+            "1": {"1": {"get_star_ts": 1733029500, "star_index": 1}, "2": {"get_star_ts": 1733030100, "star_index": 4}},
+            "2": {"1": {"get_star_ts": 1733119200, "star_index": 7}}}},
+    "2": {"id": 2, "name": null, "local_score": 4, "global_score": 0, "stars": 1, "last_star_ts": 1733029800,
+          "completion_day_level": {"1": {"1": {"get_star_ts": 1733029800, "star_index": 3}}}},
+    "3": {"id": 3, "name": "Carol", "local_score": 10, "global_score": 0, "stars": 3, "last_star_ts": 1733122800,
+          "completion_day_level": {
+            "1": {"1": {"get_star_ts": 1733029400, "star_index": 2}, "2": {"get_star_ts": 1733030400, "star_index": 5}},
+            "2": {"1": {"get_star_ts": 1733122800, "star_index": 8}}}},
+    "4": {"id": 4, "name": "Dave", "local_score": 0, "global_score": 0, "stars": 0, "last_star_ts": 0,
+          "completion_day_level": {}}
+  }
+}"#;
+
+#[test]
+fn report() {
+    assert_eq!(unlock_time(2024, 1), UNLOCK);
+
+    let leaderboard = Leaderboard::parse(EXPORT).unwrap();
+    let ranking: Vec<&str> = leaderboard
+        .ranking()
+        .iter()
+        .map(|member| member.name.as_str())
+        .collect();
+    assert_eq!(ranking, ["Alice", "Carol", "(anonymous user #2)", "Dave"]);
+
+    assert_eq!(
+        leaderboard.format_report(Some(1)),
+        "Private leaderboard 2024 (owner 1), 4 members
+
+Rank  Score  Stars  Name
+   1     10      3  Alice
+   2     10      3  Carol
+   3      4      1  (anonymous user #2)
+   4      0      0  Dave
+
+Day 1 - time since unlock, delta is Part Two - Part One
+Name                  Part One   Part Two      Delta
+Alice                  0:05:00    0:15:00    0:10:00
+Carol                  0:03:20    0:20:00    0:16:40
+(anonymous user #2)    0:10:00          -          -
+"
+    );
+    assert!(leaderboard
+        .format_report(None)
+        .contains("Day 2 - time since unlock"));
+}
+
+#[test]
+fn invalid_export() {
+    let err =
+        Leaderboard::parse(r#"{"event": "2024", "owner_id": 1, "members": {"1": {"id": 1}}}"#)
+            .err()
+            .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Invalid leaderboard: member 1 - `last_star_ts` expected."
+    );
+
+    // Unlock times are only defined from 1970
+    let err = Leaderboard::parse(r#"{"event": "1900", "owner_id": 1, "members": {}}"#)
+        .err()
+        .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "Invalid leaderboard: `event` year 1900 is before the first event (2015)."
+    );
+}
+
+/// Members with equal times on day 3 (unlock 1733202000) and equal scores.
+const TIES_EXPORT: &str = r#"{
+  "event": "2024",
+  "owner_id": 5,
+  "members": {
+    "1": {"id": 1, "name": "Erin", "local_score": 10, "stars": 2, "last_star_ts": 1733203200,
+          "completion_day_level": {"3": {"1": {"get_star_ts": 1733202600}, "2": {"get_star_ts": 1733203200}}}},
+    "2": {"id": 2, "name": "Frank", "local_score": 10, "stars": 3, "last_star_ts": 1733203200,
+          "completion_day_level": {"3": {"1": {"get_star_ts": 1733202300}, "2": {"get_star_ts": 1733203200}}}},
+    "3": {"id": 3, "name": "Bob", "local_score": 20, "stars": 2, "last_star_ts": 1733203200,
+          "completion_day_level": {"3": {"1": {"get_star_ts": 1733202300}, "2": {"get_star_ts": 1733203200}}}},
+    "4": {"id": 4, "name": "Ivy", "local_score": 20, "stars": 2, "last_star_ts": 1733202900,
+          "completion_day_level": {"3": {"1": {"get_star_ts": 1733202800}, "2": {"get_star_ts": 1733202900}}}},
+    "7": {"id": 7, "name": "Abe", "local_score": 5, "stars": 1, "last_star_ts": 1733202100,
+          "completion_day_level": {"3": {"1": {"get_star_ts": 1733202100}}}},
+    "6": {"id": 6, "name": "Gina", "local_score": 5, "stars": 1, "last_star_ts": 1733202100,
+          "completion_day_level": {"3": {"1": {"get_star_ts": 1733202100}}}}
+  }
+}"#;
+
+#[test]
+fn unlock_times() {
+    assert_eq!(unlock_time(2015, 1), 1_448_946_000);
+    assert_eq!(unlock_time(2020, 1), 1_606_798_800);
+    assert_eq!(unlock_time(2024, 25), UNLOCK + 24 * 24 * 3600);
+    // Century years - 2000 is a leap year, 2100 is not
+    assert_eq!(unlock_time(2000, 1), 975_646_800);
+    assert_eq!(unlock_time(2100, 1), 4_131_320_400);
+    assert_eq!(unlock_time(1970, 1), 28_875_600);
+}
+
+#[test]
+fn ties() {
+    let leaderboard = Leaderboard::parse(TIES_EXPORT).unwrap();
+    let ranking: Vec<&str> = leaderboard
+        .ranking()
+        .iter()
+        .map(|member| member.name.as_str())
+        .collect();
+    // Score, then stars, then the earlier last star, then id
+    assert_eq!(ranking, ["Ivy", "Bob", "Frank", "Erin", "Gina", "Abe"]);
+
+    let report = leaderboard.format_report(Some(3));
+    let (_, day_table) = report.split_once("\nDay 3").unwrap();
+    // Part Two, then Part One, then name
+    assert_eq!(
+        day_table,
+        " - time since unlock, delta is Part Two - Part One
+Name    Part One   Part Two      Delta
+Ivy      0:13:20    0:15:00    0:01:40
+Bob      0:05:00    0:20:00    0:15:00
+Frank    0:05:00    0:20:00    0:15:00
+Erin     0:10:00    0:20:00    0:10:00
+Abe      0:01:40          -          -
+Gina     0:01:40          -          -
+"
+    );
+
+    assert!(leaderboard
+        .format_report(Some(4))
+        .ends_with("Delta\nNo stars.\n"));
+}
+
+/// Local stand-in for the adventofcode.com endpoint - serves `response` to a single request and returns the request.
+fn serve(response: String) -> (String, thread::JoinHandle<String>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+
+    let server = thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut request = [0; 1024];
+        let length = stream.read(&mut request).unwrap();
+        stream.write_all(response.as_bytes()).unwrap();
+        String::from_utf8_lossy(&request[..length]).to_string()
+    });
+
+    (format!("{address}"), server)
+}
+
+#[test]
+fn load_from_endpoint() {
+    let response = format!("HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{EXPORT}");
+    let (address, server) = serve(response);
+
+    let url = format!("http://{address}/2024/leaderboard/private/view/1.json");
+    let leaderboard = Leaderboard::load(&url, Some("secret")).unwrap();
+    let request = server.join().unwrap();
+
+    assert_eq!(leaderboard.members.len(), 4);
+    assert!(request.starts_with("GET /2024/leaderboard/private/view/1.json HTTP/1.0\r\n"));
+    assert!(request.contains(&format!("Host: {address}\r\n")));
+    assert!(request.contains("Cookie: session=secret\r\n"));
+}
+
+#[test]
+fn endpoint_without_session() {
+    let (address, server) = serve(
+        "HTTP/1.1 200 OK\r\n\r\n{\"event\": \"2024\", \"owner_id\": 1, \"members\": {}}"
+            .to_string(),
+    );
+
+    let port = address.rsplit_once(':').unwrap().1;
+    let leaderboard = Leaderboard::load(&format!("http://localhost:{port}"), None).unwrap();
+    let request = server.join().unwrap();
+
+    assert!(leaderboard.members.is_empty());
+    assert!(request.starts_with("GET / HTTP/1.0\r\n"));
+    assert!(!request.contains("Cookie"));
+}
+
+#[test]
+fn cached_endpoint() {
+    let cache_dir = env::temp_dir().join(format!("aoc_cache_{}", std::process::id()));
+    let cache_dir = cache_dir.to_str().unwrap();
+
+    let response = format!("HTTP/1.0 200 OK\r\n\r\n{EXPORT}");
+    let (address, server) = serve(response);
+    let url = format!("http://{address}/1.json");
+    let fetched = Leaderboard::load_cached(&url, None, cache_dir).unwrap();
+    server.join().unwrap();
+
+    // The server only answers once, the second load has to come from the cache
+    let cached = Leaderboard::load_cached(&url, None, cache_dir).unwrap();
+    assert_eq!(cached.members.len(), fetched.members.len());
+    assert_eq!(fs::read_dir(cache_dir).unwrap().count(), 1);
+
+    fs::remove_dir_all(cache_dir).unwrap();
+}
+
+#[test]
+fn endpoint_errors() {
+    let (address, server) = serve("HTTP/1.0 404 Not Found\r\n\r\nNot found".to_string());
+    let url = format!("http://{address}/missing.json");
+    let err = Leaderboard::load(&url, None).err().unwrap();
+    server.join().unwrap();
+    assert_eq!(
+        err.to_string(),
+        format!("{url}: Request failed: HTTP/1.0 404 Not Found")
+    );
+
+    let (address, server) = serve("garbage".to_string());
+    let url = format!("http://{address}/1.json");
+    let err = Leaderboard::load(&url, None).err().unwrap();
+    server.join().unwrap();
+    assert_eq!(err.to_string(), format!("{url}: Invalid HTTP response."));
+
+    let (address, server) = serve("HTTP/1.0 200 OK\r\n\r\n{\"event\": \"2024\"}".to_string());
+    let url = format!("http://{address}/1.json");
+    let err = Leaderboard::load(&url, None).err().unwrap();
+    server.join().unwrap();
+    assert_eq!(err.file.as_deref(), Some(url.as_str()));
+}
+
+#[test]
+fn session_only_sent_to_loopback() {
+    // Refused before connecting
+    let err = Leaderboard::load(
+        "http://adventofcode.com/2024/leaderboard/private/view/1.json",
+        Some("secret"),
+    )
+    .err()
+    .unwrap();
+    assert_eq!(
+        err.to_string(),
+        "http://adventofcode.com/2024/leaderboard/private/view/1.json: Refusing to send the session cookie over \
+         plain HTTP to 'adventofcode.com', only loopback hosts are allowed."
+    );
+
+    let err = Leaderboard::load("http://192.168.1.10:8080/1.json", Some("secret"))
+        .err()
+        .unwrap();
+    assert!(err
+        .description
+        .starts_with("Refusing to send the session cookie"));
+}
@@ -0,0 +1,117 @@
+use advent_of_code::{
+    generate::Rng,
+    linear::{determinant, solve, Rational, Solution},
+    params::Params,
+    y2024::day13,
+};
+
+fn rational(numerator: i128, denominator: i128) -> Rational {
+    Rational::new(numerator, denominator).unwrap()
+}
+
+#[test]
+fn rationals_are_reduced() {
+    assert_eq!(rational(6, -4), rational(-3, 2));
+    assert_eq!(rational(-3, 2).numerator(), -3);
+    assert_eq!(rational(-3, 2).denominator(), 2);
+    assert_eq!(rational(0, -5), rational(0, 1));
+    assert_eq!(rational(10, 5).to_integer(), Some(2));
+    assert_eq!(rational(10, 4).to_integer(), None);
+    assert_eq!(rational(10, 4).to_string(), "5/2");
+    assert_eq!(Rational::new(1, 0), None);
+    assert_eq!(Rational::new(i128::MIN, -1), None);
+}
+
+#[test]
+fn two_variables() {
+    // Day 13 example machine
+    assert_eq!(
+        solve([[94, 22], [34, 67]], [8400, 5400]).unwrap(),
+        Solution::Unique([rational(80, 1), rational(40, 1)])
+    );
+    assert_eq!(
+        solve([[1, 1], [1, -1]], [1, 0]).unwrap(),
+        Solution::Unique([rational(1, 2), rational(1, 2)])
+    );
+    assert_eq!(solve([[1, 2], [2, 4]], [3, 7]).unwrap(), Solution::None);
+    assert_eq!(solve([[1, 2], [2, 4]], [3, 6]).unwrap(), Solution::Infinite);
+    assert_eq!(solve([[0, 0], [0, 0]], [0, 0]).unwrap(), Solution::Infinite);
+    assert_eq!(solve([[0, 0], [0, 0]], [0, 1]).unwrap(), Solution::None);
+
+    // Day 13 Part Two distances
+    let offset = 10_000_000_000_000;
+    let Solution::Unique([a, b]) =
+        solve([[26, 67], [66, 21]], [12748 + offset, 12176 + offset]).unwrap()
+    else {
+        panic!("Unique solution expected.");
+    };
+    assert_eq!(
+        (a.to_integer(), b.to_integer()),
+        (Some(118679050709), Some(103199174542))
+    );
+}
+
+#[test]
+fn random_systems_satisfy_equations() {
+    let mut rng = Rng::new(47);
+
+    for _ in 0..500 {
+        let mut matrix = [[0; 3]; 3];
+        for value in matrix.iter_mut().flatten() {
+            *value = i128::from(rng.range(-3..=3));
+        }
+        let x = [0; 3].map(|_| i128::from(rng.range(-10..=10)));
+        let rhs = matrix.map(|row| row.iter().zip(x).map(|(a, x)| a * x).sum());
+
+        match solve(matrix, rhs).unwrap() {
+            Solution::Unique(solution) => {
+                assert_ne!(determinant(matrix).unwrap(), 0);
+                assert_eq!(solution.map(|value| value.to_integer()), x.map(Some));
+            }
+            // Consistent by construction
+            Solution::None => panic!("{matrix:?} x = {rhs:?} has the solution {x:?}"),
+            Solution::Infinite => assert_eq!(determinant(matrix).unwrap(), 0),
+        }
+
+        // Shifted right-hand side of a singular system is usually inconsistent
+        if determinant(matrix).unwrap() == 0 {
+            let shifted = [rhs[0] + 1, rhs[1], rhs[2]];
+            let solution = solve(matrix, shifted).unwrap();
+            assert!(matches!(solution, Solution::None | Solution::Infinite));
+        }
+    }
+}
+
+#[test]
+fn determinants() {
+    assert_eq!(determinant([[2, 0, 1], [1, 3, 2], [1, 1, 2]]).unwrap(), 6);
+    assert_eq!(determinant([[0, 1], [1, 0]]).unwrap(), -1);
+    assert_eq!(determinant([[1, 2, 3], [4, 5, 6], [7, 8, 9]]).unwrap(), 0);
+    assert_eq!(determinant::<0>([]).unwrap(), 1);
+    assert!(determinant([[i128::MAX, 2], [i128::MAX, 3]]).is_err());
+}
+
+#[test]
+fn day13_collinear_buttons() {
+    let price = |machine: &str| day13::token_price(machine, &Params::default()).unwrap();
+
+    // Only B presses are the cheapest (1 token vs 3 for double the distance)
+    assert_eq!(
+        price("Button A: X+2, Y+2\nButton B: X+1, Y+1\nPrize: X=10, Y=10\n"),
+        10
+    );
+    // Single non-negative combination
+    assert_eq!(
+        price("Button A: X+4, Y+4\nButton B: X+6, Y+6\nPrize: X=10, Y=10\n"),
+        4
+    );
+    // Prize off the line or unreachable by integer presses
+    assert_eq!(
+        price("Button A: X+2, Y+2\nButton B: X+1, Y+1\nPrize: X=10, Y=11\n"),
+        0
+    );
+    assert_eq!(
+        price("Button A: X+4, Y+4\nButton B: X+6, Y+6\nPrize: X=7, Y=7\n"),
+        0
+    );
+}
@@ -0,0 +1,93 @@
+use advent_of_code::math::{crt, extended_gcd, gcd, lcm, mod_inverse, mul_mod};
+
+#[test]
+fn gcd_and_lcm_match_brute_force() {
+    for a in -30i64..=30 {
+        for b in -30i64..=30 {
+            let expected_gcd = (1..=30)
+                .rev()
+                .find(|d| a % d == 0 && b % d == 0)
+                .filter(|_| a != 0 || b != 0)
+                .unwrap_or(0);
+            assert_eq!(gcd(a, b), Some(expected_gcd), "gcd({a}, {b})");
+
+            let expected_lcm = match a == 0 || b == 0 {
+                true => 0,
+                false => (1..).find(|m| m % a == 0 && m % b == 0).unwrap(),
+            };
+            assert_eq!(lcm(a, b), Some(expected_lcm), "lcm({a}, {b})");
+
+            let (g, x, y) = extended_gcd(a, b).unwrap();
+            assert_eq!(g, expected_gcd);
+            assert_eq!(a * x + b * y, g, "extended_gcd({a}, {b})");
+        }
+    }
+}
+
+#[test]
+fn extremes_dont_overflow() {
+    assert_eq!(gcd(i64::MIN, 0), None);
+    assert_eq!(gcd(i64::MIN, i64::MIN), None);
+    assert_eq!(gcd(i64::MIN, -1), Some(1));
+    assert_eq!(gcd(i64::MIN, 6), Some(2));
+    assert_eq!(gcd(i128::MAX, i128::MAX - 1), Some(1));
+
+    assert_eq!(lcm(i64::MAX, i64::MAX - 1), None);
+    assert_eq!(lcm(i64::MAX, 1), Some(i64::MAX));
+    assert_eq!(lcm(i64::MIN, 2), None);
+
+    let (a, b) = (i128::MAX, i128::MAX - 2);
+    let (g, x, y) = extended_gcd(a, b).unwrap();
+    assert_eq!(g, 1);
+    assert!(x.abs() <= b && y.abs() <= a);
+
+    // Product doesn't fit - computed by doubling
+    let modulus = i128::MAX - 10;
+    assert_eq!(mul_mod(modulus - 1, modulus - 1, modulus), Some(1));
+    assert_eq!(mul_mod(-1, modulus - 1, modulus), Some(1));
+    assert_eq!(mul_mod(3, 4, 0), None);
+}
+
+#[test]
+fn modular_inverse() {
+    for modulus in 1i64..50 {
+        for a in -60..60 {
+            let expected = (0..modulus).find(|x| (a * x).rem_euclid(modulus) == 1 % modulus);
+            assert_eq!(mod_inverse(a, modulus), expected, "{a}^-1 mod {modulus}");
+        }
+    }
+
+    assert_eq!(mod_inverse(3, 0), None);
+    assert_eq!(mod_inverse(3, -7), None);
+    let modulus = i128::MAX; // 2^127 - 1 is prime
+    let inverse = mod_inverse(12345, modulus).unwrap();
+    assert_eq!(mul_mod(12345, inverse, modulus), Some(1));
+}
+
+#[test]
+fn chinese_remainder_theorem() {
+    // Robots repeat their columns every 101 and rows every 103 iterations
+    for (column_time, row_time) in [(0, 0), (12, 80), (100, 102), (-1, -1)] {
+        let (x, lcm) = crt(&[(column_time, 101i64), (row_time, 103)]).unwrap();
+        assert_eq!(lcm, 10_403);
+        assert!((0..lcm).contains(&x));
+        assert_eq!(x % 101, column_time.rem_euclid(101));
+        assert_eq!(x % 103, row_time.rem_euclid(103));
+    }
+
+    // Moduli with common factors
+    assert_eq!(crt(&[(2i64, 6), (8, 10)]), Some((8, 30)));
+    assert_eq!(crt(&[(1i64, 6), (2, 4)]), None);
+    assert_eq!(crt(&[(3i64, 4), (3, 4), (1, 2)]), Some((3, 4)));
+
+    assert_eq!(crt::<i64>(&[]), Some((0, 1)));
+    assert_eq!(crt(&[(1i64, 0)]), None);
+
+    // Solution close to the limits of i128
+    let (p, q) = (i128::MAX, (1i128 << 61) - 1); // Mersenne primes
+    assert_eq!(crt(&[(1, p), (2, q)]), None);
+    let (p, q) = ((1i128 << 61) - 1, (1i128 << 31) - 1);
+    let (x, lcm) = crt(&[(p - 1, p), (q - 2, q)]).unwrap();
+    assert_eq!(lcm, p * q);
+    assert_eq!((x % p, x % q), (p - 1, q - 2));
+}
@@ -0,0 +1,58 @@
+use advent_of_code::{
+    animation,
+    input::{input_path, read_input},
+    params::Params,
+    playback::{Key, Player},
+    registry::Part,
+};
+
+#[test]
+fn keys_parse() {
+    assert_eq!(
+        Key::parse(b" n\x1b[Cb\x1b[D12gx+-q"),
+        [
+            Key::PlayPause,
+            Key::Forward,
+            Key::Forward,
+            Key::Back,
+            Key::Back,
+            Key::Digit(1),
+            Key::Digit(2),
+            Key::Jump,
+            Key::Faster,
+            Key::Slower,
+            Key::Quit,
+        ]
+    );
+}
+
+/// Stepping across recorded batches, jumping past the end and playing to the end.
+#[test]
+fn player_navigates() {
+    let animation = animation::find(2024, 15, Some(Part::One)).unwrap();
+    let input = read_input(&input_path(2024, 15)).unwrap();
+    let params = Params::default();
+    let mut player = Player::new(animation, &input, &params, 100, 10).unwrap();
+
+    for key in Key::parse(b"bbb") {
+        player.handle(key).unwrap();
+    }
+    assert_eq!(player.iteration(), 97);
+
+    for key in Key::parse(b"1000000g") {
+        player.handle(key).unwrap();
+    }
+    let last = player.iteration();
+    assert_eq!(player.snapshot().metrics[0], ("gps sum", 1465152));
+
+    for key in Key::parse(b"b ") {
+        player.handle(key).unwrap();
+    }
+    assert!(player.is_playing());
+    player.tick().unwrap();
+    player.tick().unwrap();
+    assert_eq!(player.iteration(), last);
+    assert!(!player.is_playing());
+
+    assert!(!player.handle(Key::Quit).unwrap());
+}
@@ -0,0 +1,80 @@
+use std::{collections::BTreeSet, ops::Range};
+
+use advent_of_code::{generate::Rng, range_set::RangeSet};
+
+/// Maximal ranges of a plain set of values.
+fn ranges_of(values: &BTreeSet<i64>) -> Vec<Range<i64>> {
+    let mut ranges: Vec<Range<i64>> = vec![];
+    for &value in values {
+        match ranges.last_mut() {
+            Some(last) if last.end == value => last.end += 1,
+            _ => ranges.push(value..value + 1),
+        }
+    }
+
+    ranges
+}
+
+#[test]
+fn random_operations_match_plain_set() {
+    let mut rng = Rng::new(45);
+
+    for _ in 0..200 {
+        let mut set = RangeSet::new();
+        let mut expected = BTreeSet::new();
+
+        for _ in 0..30 {
+            let start = rng.range(-20..=60);
+            let range = start..start + rng.range(-2..=12);
+            match rng.chance(2, 3) {
+                true => {
+                    set.insert(range.clone());
+                    expected.extend(range);
+                }
+                false => {
+                    set.remove(range.clone());
+                    for value in range {
+                        expected.remove(&value);
+                    }
+                }
+            }
+
+            assert_eq!(set.iter().collect::<Vec<_>>(), ranges_of(&expected));
+            assert_eq!(set.len(), ranges_of(&expected).len());
+            let value = rng.range(-25..=75);
+            assert_eq!(set.contains(value), expected.contains(&value));
+        }
+
+        let at = rng.range(-25..=75);
+        let mut lower = set.clone();
+        let upper = lower.split_off(at);
+        let (expected_lower, expected_upper): (BTreeSet<i64>, BTreeSet<i64>) =
+            expected.iter().partition(|&&value| value < at);
+        assert_eq!(lower.iter().collect::<Vec<_>>(), ranges_of(&expected_lower));
+        assert_eq!(upper.iter().collect::<Vec<_>>(), ranges_of(&expected_upper));
+
+        lower.merge(&upper);
+        assert_eq!(lower, set);
+    }
+}
+
+#[test]
+fn merges_adjacent_ranges() {
+    let set: RangeSet<u32> = [0..2, 5..7, 2..3, 7..7, 4..5].into_iter().collect();
+    assert_eq!(set.iter().collect::<Vec<_>>(), vec![0..3, 4..7]);
+    assert_eq!(set.range_containing(5), Some(4..7));
+    assert_eq!(set.range_containing(3), None);
+}
+
+#[test]
+fn first_fit() {
+    let mut free_space: RangeSet<usize> = [2..5, 8..9, 11..14, 20..30].into_iter().collect();
+    assert_eq!(free_space.first_fit(1), Some(2..5));
+    assert_eq!(free_space.first_fit(4), Some(20..30));
+    assert_eq!(free_space.first_fit(11), None);
+
+    // File of length 3 moved to the first fit
+    free_space.remove(2..5);
+    assert_eq!(free_space.first_fit(3), Some(11..14));
+    assert!(!free_space.is_empty());
+}
@@ -0,0 +1,223 @@
+use std::{env, fs, path::Path};
+
+use advent_of_code::{
+    answers::{input_name, AnswerStore},
+    config::{Config, OutputFormat},
+    error::Error,
+    params::Params,
+    registry::{Outcome, Part, Solution, PARTS},
+    runner::{format_report, run_inputs, Check, InputReport},
+};
+
+const ANSWERS: &str = "
+# year day input part_one part_two
+2024 1 good 3 6      # both known
+2024 1 wrong 1 -     # Part One disagrees, Part Two unknown
+2024 1 broken 1 2
+2024 2 good 100 200  # other day
+";
+
+/// Part One counts lines, Part Two sums numbers - fails for a non-number, panics on `panic`.
+const SOLUTION: Solution = Solution {
+    year: 2024,
+    day: 1,
+    part_one: |input, _| Ok(input.lines().count().to_string()),
+    part_two: |input, _| {
+        let mut sum = 0;
+        for (i, line) in input.lines().enumerate() {
+            if line == "panic" {
+                panic!("Solver gave up.");
+            }
+            sum += line
+                .parse::<u32>()
+                .map_err(|_| Error::at_line(i + 1, format!("Invalid value '{line}'.")))?;
+        }
+        Ok(sum.to_string())
+    },
+    parameters: &["offset"],
+};
+
+#[test]
+fn part_numbers() {
+    let numbers: Vec<usize> = PARTS.iter().map(|part| part.number()).collect();
+    assert_eq!(numbers, [1, 2]);
+}
+
+#[test]
+fn answers_file() {
+    let store = AnswerStore::parse(ANSWERS).unwrap();
+
+    assert_eq!(store.get(2024, 1, "good", Part::One), Some("3"));
+    assert_eq!(store.get(2024, 1, "good", Part::Two), Some("6"));
+    assert_eq!(store.get(2024, 1, "wrong", Part::Two), None);
+    assert_eq!(store.get(2024, 2, "good", Part::Two), Some("200"));
+    assert_eq!(store.get(2024, 3, "good", Part::One), None);
+    assert_eq!(store.get(2024, 1, "other", Part::One), None);
+
+    assert_eq!(input_name("resources/2024/01/good.txt"), "good");
+    assert_eq!(input_name("./resources/2024_01.txt"), "2024_01");
+
+    let error = |content: &str| AnswerStore::parse(content).err().unwrap().to_string();
+    assert_eq!(
+        error("2024 1 good 3\n"),
+        "1: Expected `year day input part_one part_two`."
+    );
+    assert_eq!(
+        error("\n2024 1 good 3 6 # comment\n2024 x good 3 6\n"),
+        "3:6: Invalid value 'x'."
+    );
+    assert_eq!(error("70000 1 good 3 6"), "1:1: Invalid value '70000'.");
+}
+
+/// Inputs in a temporary directory - `(name, content)`, returns their paths.
+fn write_inputs(dir: &str, inputs: &[(&str, &str)]) -> Vec<String> {
+    let dir = env::temp_dir().join(format!("aoc_runner_{dir}_{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+
+    inputs
+        .iter()
+        .map(|(name, content)| {
+            let path = dir.join(format!("{name}.txt"));
+            fs::write(&path, content).unwrap();
+            path.to_str().unwrap().to_string()
+        })
+        .collect()
+}
+
+fn checks(report: &InputReport) -> Vec<(&Outcome, &Check)> {
+    report
+        .parts
+        .iter()
+        .map(|part_report| (&part_report.outcome, &part_report.check))
+        .collect()
+}
+
+fn answer(value: &str) -> Outcome {
+    Outcome::Answer(value.to_string())
+}
+
+#[test]
+fn run_and_flag_inputs() {
+    let mut paths = write_inputs(
+        "flags",
+        &[
+            ("good", "1\n2\n3\n"),
+            ("wrong", "4\n5\n"),
+            ("broken", "1\nx\n"),
+            ("crash", "1\npanic\n"),
+            ("unknown", "7\n"),
+        ],
+    );
+    paths.push("./resources/missing.txt".to_string());
+    let store = AnswerStore::parse(ANSWERS).unwrap();
+
+    for threads in [1, 4] {
+        let config = Config {
+            threads,
+            ..Config::default()
+        };
+        let reports = run_inputs(&SOLUTION, &paths, &store, &config, &Params::default());
+
+        // Order of the paths is kept also with more threads
+        let report_paths: Vec<&str> = reports.iter().map(|report| report.path.as_str()).collect();
+        assert_eq!(report_paths, paths);
+
+        assert_eq!(
+            checks(&reports[0]),
+            [
+                (&answer("3"), &Check::Correct),
+                (&answer("6"), &Check::Correct)
+            ]
+        );
+        assert_eq!(
+            checks(&reports[1]),
+            [
+                (
+                    &answer("2"),
+                    &Check::Incorrect {
+                        expected: "1".to_string()
+                    }
+                ),
+                (&answer("9"), &Check::Unknown)
+            ]
+        );
+        // Failed part with a known answer is incorrect
+        assert_eq!(
+            checks(&reports[2]),
+            [
+                (
+                    &answer("2"),
+                    &Check::Incorrect {
+                        expected: "1".to_string()
+                    }
+                ),
+                (
+                    &Outcome::Failed(Error::at_line(2, "Invalid value 'x'.")),
+                    &Check::Incorrect {
+                        expected: "2".to_string()
+                    }
+                )
+            ]
+        );
+        assert_eq!(
+            checks(&reports[3])[1],
+            (
+                &Outcome::Panicked("Solver gave up.".to_string()),
+                &Check::Unknown
+            )
+        );
+        assert!(reports[4]
+            .parts
+            .iter()
+            .all(|part_report| part_report.check == Check::Unknown));
+        assert!(reports[5].parts.is_empty());
+        assert!(reports[5].read_error.is_some());
+
+        let flagged: Vec<bool> = reports.iter().map(InputReport::is_flagged).collect();
+        assert_eq!(flagged, [false, true, true, true, false, true]);
+    }
+
+    remove_inputs(&paths[0]);
+}
+
+#[test]
+fn parameters_and_reports() {
+    let paths = write_inputs("params", &[("good", "1\n2\n3\n"), ("typo", "1\n")]);
+    fs::write(paths[1].replace(".txt", ".params"), "ofset = 1\n").unwrap();
+    let store = AnswerStore::parse(ANSWERS).unwrap();
+
+    let reports = run_inputs(
+        &SOLUTION,
+        &paths,
+        &store,
+        &Config::default(),
+        &Params::default(),
+    );
+    let read_error = reports[1].read_error.as_deref().unwrap();
+    assert!(read_error.starts_with("Unknown parameter 'ofset' for 2024/01"));
+    assert!(reports[1].is_flagged());
+
+    let tsv = format_report(&reports, OutputFormat::Tsv);
+    let lines: Vec<&str> = tsv.lines().collect();
+    assert_eq!(lines[0], "path\tpart\tstatus\tanswer\texpected");
+    assert_eq!(lines[1], format!("{}\t1\tok\t3\t", paths[0]));
+    assert_eq!(lines[2], format!("{}\t2\tok\t6\t", paths[0]));
+    assert_eq!(
+        lines[3],
+        format!("{}\t\tunreadable\t{read_error}\t", paths[1])
+    );
+
+    let table = format_report(&reports, OutputFormat::Table);
+    let rows: Vec<&str> = table.lines().collect();
+    assert!(
+        rows[0].contains("Input") && rows[0].contains("Part One") && rows[0].contains("Part Two")
+    );
+    assert!(rows[1].starts_with("  ") && rows[1].contains("3 ✓") && rows[1].contains("6 ✓"));
+    assert!(rows[2].starts_with("! ") && rows[2].contains(read_error));
+
+    remove_inputs(&paths[0]);
+}
+
+fn remove_inputs(path: &str) {
+    fs::remove_dir_all(Path::new(path).parent().unwrap()).unwrap();
+}
@@ -0,0 +1,92 @@
+use std::{env, fs, time::Duration};
+
+use advent_of_code::{
+    answers::AnswerStore,
+    registry::Part,
+    stats::{format_stats, summarize, Entry, Event, Ledger},
+};
+
+/// 2024-12-01 00:00 in the Advent of Code time zone (UTC-5)
+const UNLOCK: u64 = 1_733_029_200;
+const DAY: u64 = 24 * 3600;
+
+fn entry(timestamp: u64, day: u8, part: Part, input: &str, event: Event) -> Entry {
+    Entry {
+        timestamp,
+        year: 2024,
+        day,
+        part,
+        input: input.to_string(),
+        event,
+    }
+}
+
+fn attempt(answer: &str) -> Event {
+    Event::Attempt {
+        answer: answer.to_string(),
+    }
+}
+
+fn run(millis: u64, answer: Option<&str>) -> Event {
+    Event::Run {
+        elapsed: Duration::from_millis(millis),
+        answer: answer.map(str::to_string),
+    }
+}
+
+#[test]
+fn ledger_round_trip() {
+    let path = env::temp_dir().join(format!("aoc_stats_{}.txt", std::process::id()));
+    let path = path.to_str().unwrap();
+    let entries = [
+        entry(UNLOCK, 1, Part::One, "2024_01", run(3, Some("11"))),
+        entry(UNLOCK, 1, Part::Two, "2024_01", run(5, None)),
+        entry(UNLOCK + 60, 1, Part::One, "2024_01", attempt("with\ttab")),
+    ];
+
+    Ledger::append(path, &entries[..1]).unwrap();
+    Ledger::append(path, &entries[1..]).unwrap();
+    let ledger = Ledger::load(path).unwrap();
+    fs::remove_file(path).unwrap();
+
+    assert_eq!(ledger.entries[..2], entries[..2]);
+    assert_eq!(ledger.entries[2].event, attempt("with tab"));
+}
+
+#[test]
+fn solve_times_attempts_and_streaks() {
+    let store = AnswerStore::parse("2024 1 2024_01 11 31\n2024 2 2024_02 2 -\n").unwrap();
+    let ledger = Ledger {
+        entries: vec![
+            entry(UNLOCK + 300, 1, Part::One, "2024_01", run(3, Some("10"))),
+            entry(UNLOCK + 400, 1, Part::One, "2024_01", attempt("10")),
+            entry(UNLOCK + 500, 1, Part::One, "2024_01", attempt("10")),
+            entry(UNLOCK + 600, 1, Part::One, "2024_01", run(2, Some("11"))),
+            entry(UNLOCK + 900, 1, Part::Two, "2024_01", attempt("31")),
+            // Not the personal input
+            entry(UNLOCK, 2, Part::One, "example", run(1, Some("2"))),
+            entry(UNLOCK + DAY + 3600, 2, Part::One, "2024_02", attempt("2")),
+        ],
+    };
+
+    let days = summarize(&ledger, &store, |year, day| format!("{year}_{day:02}"));
+
+    assert_eq!(days.len(), 2);
+    assert_eq!(days[0].started, UNLOCK + 300);
+    assert_eq!(days[0].parts[0].solved, Some(UNLOCK + 600));
+    assert_eq!(days[0].parts[0].attempts, 1);
+    assert_eq!(days[0].parts[0].wrong_attempts, 1);
+    assert_eq!(days[0].parts[0].best_time, Some(Duration::from_millis(2)));
+    assert_eq!(days[0].parts[1].solved, Some(UNLOCK + 900));
+    assert_eq!(days[1].parts[0].solved, Some(UNLOCK + DAY + 3600));
+
+    let stats = format_stats(&days, UNLOCK + 2 * DAY);
+    assert_eq!(
+        stats,
+        "2024: 3 stars, 2 days started, 3 attempts (1 wrong), streak 2 (longest 2)\n\
+         Day  Started           Part One  Part Two  Attempts  Solver\n  \
+         1  2024-12-01 00:05   0:05:00   0:10:00         2  2.00ms / -\n  \
+         2  2024-12-02 01:00   0:00:00         -         1  - / -\n"
+    );
+    assert!(format_stats(&days, UNLOCK + 3 * DAY).contains("streak 0 (longest 2)"));
+}
@@ -0,0 +1,72 @@
+use advent_of_code::{
+    params::Params,
+    text::{blocks, ints, ints_vec, ints_with_columns},
+    y2024::{day13, day14},
+};
+
+#[test]
+fn numbers_in_a_line() {
+    assert_eq!(ints::<u64, 2>("Button A: X+83, Y+59"), Ok([83, 59]));
+    assert_eq!(ints::<i32, 4>("p=63,14 v=-55,-32"), Ok([63, 14, -55, -32]));
+    // Minus is a separator for unsigned types
+    assert_eq!(ints::<u8, 2>("3-4"), Ok([3, 4]));
+    assert_eq!(ints::<i8, 2>("3-4"), Ok([3, -4]));
+    assert_eq!(ints::<i64, 0>("no numbers - here"), Ok([]));
+
+    assert_eq!(ints_vec::<usize>("1, 22,333"), Ok(vec![1, 22, 333]));
+    assert_eq!(ints_vec::<isize>("--5"), Ok(vec![-5]));
+    assert_eq!(
+        ints_with_columns::<i32, 2>("x=-1, y=20"),
+        Ok([(-1, 3), (20, 9)])
+    );
+}
+
+#[test]
+fn clear_errors() {
+    let err = ints::<u64, 2>("Prize: X=1, Y=2, Z=3").unwrap_err();
+    assert_eq!(err.to_string(), "1: Expected 2 numbers, found 3.");
+    assert_eq!(
+        err.with_line(7).to_string(),
+        "7: Expected 2 numbers, found 3."
+    );
+
+    let err = ints_vec::<u8>("1 256").unwrap_err();
+    assert_eq!(err.to_string(), "1:3: Number '256' doesn't fit u8.");
+    assert!(ints::<u8, 1>("-1").is_ok());
+    assert!(ints::<i8, 1>("-129").is_err());
+}
+
+#[test]
+fn blank_line_separated_blocks() {
+    let content = "a\nb\n\nc\n \n\n\nd\ne\nf\n\n";
+    let found: Vec<(usize, Vec<&str>)> = blocks(content).collect();
+    assert_eq!(
+        found,
+        vec![
+            (1, vec!["a", "b"]),
+            (4, vec!["c"]),
+            (8, vec!["d", "e", "f"])
+        ]
+    );
+
+    assert_eq!(blocks("").count(), 0);
+    assert_eq!(blocks("\n\n").count(), 0);
+}
+
+#[test]
+fn days_13_and_14_report_positions() {
+    let machines = "Button A: X+1, Y+2\nButton B: X+3, Y+4\nPrize: X=5, Y=6\n\n\
+                    Button A: X+1, Y+2\nButton B: X+3\nPrize: X=5, Y=6\n";
+    let err = day13::token_price(machines, &Params::default()).unwrap_err();
+    assert_eq!(err.to_string(), "6: Expected 2 numbers, found 1.");
+
+    let err = day13::token_price("Button A: X+1, Y+2\n", &Params::default()).unwrap_err();
+    assert_eq!(err.line, Some(1));
+
+    let robots = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,200 v=-1,2\n";
+    let err = day14::safety_factor(robots, &Params::default()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "3:6: Position 200 out of grid bounds 0..103."
+    );
+}
@@ -0,0 +1,397 @@
+use advent_of_code::{
+    animation::{Recording, Snapshot},
+    error::InFile,
+    input::{example_paths, read_input},
+    params::Params,
+    y2024::{
+        day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13,
+        day14, day15,
+    },
+};
+
+/// Example inputs of the day (`resources/2024_<day>_example*.txt`) with their parameters.
+fn examples(day: u8) -> Vec<(String, Params)> {
+    let paths = example_paths(2024, day);
+    assert!(!paths.is_empty(), "day {day} has no example");
+
+    paths
+        .iter()
+        .map(|path| {
+            (
+                read_input(path).unwrap(),
+                Params::load_for_input(path).unwrap(),
+            )
+        })
+        .collect()
+}
+
+/// The only example of the day.
+fn example(day: u8) -> (String, Params) {
+    let mut examples = examples(day);
+    assert_eq!(examples.len(), 1, "day {day} has more examples");
+
+    examples.remove(0)
+}
+
+fn metric(snapshot: &Snapshot, name: &str) -> usize {
+    snapshot
+        .metrics
+        .iter()
+        .find(|(metric_name, _)| *metric_name == name)
+        .map(|(_, value)| *value)
+        .unwrap_or_else(|| panic!("No metric '{name}'."))
+}
+
+fn all_frames(step: usize) -> Recording {
+    Recording {
+        start: 0,
+        step,
+        frames: usize::MAX,
+    }
+}
+
+#[test]
+fn day01() {
+    let (input, _) = example(1);
+    assert_eq!(day01::total_distance(&input), Ok(11));
+    assert_eq!(day01::similarity_score(&input), Ok(31));
+}
+
+#[test]
+fn day01_overflow() {
+    assert_eq!(
+        day01::total_distance("-2147483648 2147483647").map_err(|err| err.to_string()),
+        Err("Total distance overflowed i32.".to_string())
+    );
+    assert_eq!(
+        day01::total_distance("2000000000 0\n0 -2000000000").map_err(|err| err.to_string()),
+        Err("Total distance overflowed i32.".to_string())
+    );
+    assert_eq!(
+        day01::similarity_score("1 3\n3 3\n1073741824 1073741824\n1 1073741824")
+            .map_err(|err| err.to_string()),
+        Err("3: Similarity score overflowed i32.".to_string())
+    );
+}
+
+#[test]
+fn day02() {
+    let (input, _) = example(2);
+    assert_eq!(day02::safe_report_count(&input), Ok(2));
+    assert_eq!(day02::safe_report_count_with_dampener(&input), Ok(4));
+}
+
+#[test]
+fn day02_extreme_levels() {
+    // Differences don't fit i32
+    assert_eq!(day02::safe_report_count("2147483647 -2147483648"), Ok(0));
+    assert_eq!(
+        day02::safe_report_count_with_dampener("-2147483648 2147483647 -2147483647 -2147483645"),
+        Ok(1)
+    );
+    assert_eq!(
+        day02::safe_report_count("2147483645 2147483647\n-2147483648 -2147483646"),
+        Ok(2)
+    );
+}
+
+#[test]
+fn day02_error_positions() {
+    let path = "./resources/2024_02.txt";
+    let error = |input: &str| {
+        day02::safe_report_count(input)
+            .in_file(path)
+            .unwrap_err()
+            .to_string()
+    };
+
+    assert_eq!(
+        error("1 2 3\n4 x 6\n"),
+        "./resources/2024_02.txt:2:3: Invalid value 'x'."
+    );
+    assert_eq!(
+        error("1 2 3\n\n7  8   2147483648"),
+        "./resources/2024_02.txt:3:8: Invalid value '2147483648'."
+    );
+    assert_eq!(
+        day02::safe_report_count_with_dampener("1 -2 3.5")
+            .unwrap_err()
+            .to_string(),
+        "1:6: Invalid value '3.5'."
+    );
+}
+
+#[test]
+fn day03() {
+    let sums: Vec<_> = examples(3)
+        .iter()
+        .map(|(input, _)| {
+            (
+                day03::multiplication_sum(input),
+                day03::enabled_multiplication_sum(input),
+            )
+        })
+        .collect();
+    assert_eq!(sums, [(Ok(161), Ok(161)), (Ok(161), Ok(48))]);
+}
+
+#[test]
+fn day03_overflow() {
+    // The 2152nd product pushes the sum past i32::MAX
+    let input = "mul(999,999)\n".repeat(3000);
+    assert_eq!(
+        day03::multiplication_sum(&input).map_err(|err| err.to_string()),
+        Err("2152:1: Sum of products overflowed i32.".to_string())
+    );
+    assert_eq!(
+        day03::enabled_multiplication_sum(&format!("don't()x{input}do()mul(1,2)"))
+            .map_err(|err| err.to_string()),
+        Ok(2)
+    );
+    assert_eq!(
+        day03::enabled_multiplication_sum("do()\n  mul(99999,99999)")
+            .map_err(|err| err.to_string()),
+        Err("2:3: Result of 'mul(99999,99999)' overflowed i32.".to_string())
+    );
+}
+
+#[test]
+fn day04() {
+    let (input, _) = example(4);
+    assert_eq!(day04::xmas_count(&input), Ok(18));
+    assert_eq!(day04::x_mas_count(&input), Ok(9));
+}
+
+#[test]
+fn day05() {
+    let (input, _) = example(5);
+    assert_eq!(day05::valid_middle_sum(&input), Ok(143));
+    assert_eq!(day05::sorted_middle_sum(&input), Ok(123));
+}
+
+#[test]
+fn day05_repeated_page() {
+    assert_eq!(
+        day05::sorted_middle_sum("2|1\n\n1,2,1\n").map_err(|err| err.to_string()),
+        Err("3:5: Page 1 is repeated in the update.".to_string())
+    );
+    assert_eq!(
+        day05::valid_middle_sum("10|20\n\n10,20,30\n10,20,30,40,20\n")
+            .map_err(|err| err.to_string()),
+        Err("4:13: Page 20 is repeated in the update.".to_string())
+    );
+}
+
+#[test]
+fn day06() {
+    let (input, _) = example(6);
+    assert_eq!(day06::visited_count(&input), Ok(41));
+    assert_eq!(day06::cycle_count(&input), Ok(6));
+
+    let snapshots = day06::record_walk(&input, &all_frames(1)).unwrap();
+    let last = snapshots.last().unwrap();
+    assert_eq!(snapshots[0].iteration, 0);
+    assert_eq!(metric(&snapshots[0], "visited"), 1);
+    assert_eq!(metric(last, "visited"), 41);
+    // Visited points are `X` except the one with the guard
+    let points = last.grid.get_rows().concat();
+    assert_eq!(points.iter().filter(|&&point| point == 'X').count(), 40);
+    assert_eq!(
+        points
+            .iter()
+            .filter(|&&point| "^>v<".contains(point))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn day07() {
+    let (input, _) = example(7);
+    assert_eq!(day07::calibration_result(&input), Ok(3749));
+    assert_eq!(
+        day07::calibration_result_with_concatenation(&input),
+        Ok(11387)
+    );
+}
+
+#[test]
+fn day08() {
+    let (input, _) = example(8);
+    assert_eq!(day08::antinode_count(&input), Ok(14));
+    assert_eq!(day08::repeated_antinode_count(&input), Ok(34));
+}
+
+#[test]
+fn day09() {
+    let (input, _) = example(9);
+    assert_eq!(day09::checksum_by_block(&input), Ok(1928));
+    assert_eq!(day09::checksum_by_file(&input), Ok(2858));
+}
+
+#[test]
+fn day10() {
+    let (input, params) = example(10);
+    assert_eq!(day10::score_sum(&input, &params), Ok(36));
+    assert_eq!(day10::rating_sum(&input, &params), Ok(81));
+}
+
+#[test]
+fn day11() {
+    let (input, _) = example(11);
+    assert_eq!(day11::stone_count(&input, 6), Ok(22));
+    assert_eq!(
+        day11::stone_count(&input, day11::PART_ONE_BLINKS),
+        Ok(55312)
+    );
+    assert_eq!(
+        day11::stone_count(&input, day11::PART_TWO_BLINKS),
+        Ok(65601038650482)
+    );
+}
+
+#[test]
+fn day12() {
+    let prices: Vec<_> = examples(12)
+        .iter()
+        .map(|(input, _)| {
+            (
+                day12::fence_price_perimeter(input),
+                day12::fence_price_sides(input),
+            )
+        })
+        .collect();
+    assert_eq!(
+        prices,
+        [(Ok(140), Ok(80)), (Ok(772), Ok(436)), (Ok(1930), Ok(1206))]
+    );
+}
+
+#[test]
+fn day13() {
+    let (input, params) = example(13);
+    assert_eq!(day13::token_price(&input, &params), Ok(480));
+    assert_eq!(
+        day13::token_price_increased_distance(&input, &params),
+        Ok(875318608908)
+    );
+}
+
+#[test]
+fn day14() {
+    let (input, params) = example(14);
+    assert_eq!(day14::safety_factor(&input, &params), Ok(12));
+
+    // Picture from the puzzle text after 100 seconds
+    let expected = [
+        "......2..1.",
+        "...........",
+        "1..........",
+        ".11........",
+        ".....1.....",
+        "...12......",
+        ".1....1....",
+    ];
+    let picture = day14::render_robots(&input, &params, 100).unwrap();
+    let rows: Vec<String> = expected
+        .iter()
+        .map(|row| {
+            row.chars()
+                .map(|point| if point == '.' { ' ' } else { 'X' })
+                .collect()
+        })
+        .collect();
+    assert_eq!(picture, rows.join("\n") + "\n");
+
+    let recording = Recording {
+        start: 0,
+        step: 50,
+        frames: 3,
+    };
+    let snapshots = day14::record_robots(&input, &params, &recording).unwrap();
+    assert_eq!(snapshots.len(), 3);
+    assert_eq!(snapshots[2].iteration, 100);
+    assert_eq!(metric(&snapshots[2], "safety factor"), 12);
+
+    // The example robots never form an image
+    assert_eq!(
+        day14::easter_egg_iterations(&input, &params).map_err(|err| err.to_string()),
+        Err("No image found within the robots' period of 77 iterations.".to_string())
+    );
+
+    // Row of robots that line up after 2 seconds (5 neighbours to the right) and meet in one point after 1
+    let robots = "p=0,3 v=0,0\np=10,3 v=1,0\np=9,3 v=2,0\np=8,3 v=3,0\np=7,3 v=4,0\np=6,3 v=5,0\n";
+    let params = Params::parse_overrides("x_len=11,y_len=7,heuristic_threshold=4").unwrap();
+    assert_eq!(day14::easter_egg_iterations(robots, &params), Ok(2));
+}
+
+#[test]
+fn day15() {
+    let sums: Vec<_> = examples(15)
+        .iter()
+        .map(|(input, _)| (day15::gps_sum(input), day15::gps_sum_enlarged(input)))
+        .collect();
+    assert_eq!(sums[0].0, Ok(2028));
+    assert_eq!(sums[1], (Ok(10092), Ok(9021)));
+    // Part Two answer of the last example is not in the puzzle text - it ends with boxes at 105, 207 and 306
+    assert_eq!(sums[2].1, Ok(618));
+
+    let (input, _) = &examples(15)[0];
+    for enlarged in [false, true] {
+        let snapshots = day15::record_moves(input, enlarged, &all_frames(5)).unwrap();
+        let iterations: Vec<usize> = snapshots
+            .iter()
+            .map(|snapshot| snapshot.iteration)
+            .collect();
+        assert_eq!(iterations, [0, 5, 10, 15]);
+
+        let last = snapshots.last().unwrap();
+        assert_eq!(metric(last, "moves left"), 0);
+        let expected = match enlarged {
+            false => day15::gps_sum(input),
+            true => day15::gps_sum_enlarged(input),
+        };
+        assert_eq!(Ok(metric(last, "gps sum")), expected);
+    }
+}
+
+#[test]
+fn day11_error_positions() {
+    assert_eq!(
+        day11::stone_count("125 17\n\n  0 x1", 6).map_err(|err| err.to_string()),
+        Err("3:5: Invalid stone value 'x1'.".to_string())
+    );
+}
+
+#[test]
+fn day11_stone_count_overflow() {
+    assert_eq!(
+        day11::stone_count("125 17", 400).unwrap_err().to_string(),
+        "Stone count overflowed usize."
+    );
+}
+
+#[test]
+fn day14_large_iteration_counts() {
+    let robots = "p=0,4 v=3,-3\np=6,3 v=-1,-3\np=10,3 v=-1,2\np=2,0 v=2,-1\n";
+    let factor =
+        |params: &str| day14::safety_factor(robots, &Params::parse_overrides(params).unwrap());
+
+    // Positions repeat every 11 * 7 iterations in the example room
+    let room = "x_len=11,y_len=7";
+    let huge = 100_000_000_000_000_000usize;
+    assert_eq!(
+        factor(&format!("{room},iter_count={huge}")),
+        factor(&format!("{room},iter_count={}", huge % 77))
+    );
+    assert_eq!(
+        factor(&format!("{room},iter_count={}", usize::MAX)),
+        factor(&format!("{room},iter_count={}", usize::MAX % 77))
+    );
+
+    let err = factor(&format!("x_len={},y_len=7", usize::MAX)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        format!("Room size {} doesn't fit isize.", usize::MAX)
+    );
+}